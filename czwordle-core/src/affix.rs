@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests;
+
+// Affix rules of a Hunspell `.aff` file, only single-character flags (the default `FLAG` type) are supported.
+#[derive(Clone, Debug, Default)]
+pub struct Affixes {
+    prefixes: HashMap<char, AffixClass>,
+    suffixes: HashMap<char, AffixClass>,
+}
+
+#[derive(Clone, Debug)]
+struct AffixClass {
+    cross_product: bool,
+    rules: Vec<AffixRule>,
+}
+
+#[derive(Clone, Debug)]
+struct AffixRule {
+    strip: String,
+    add: String,
+    condition: Vec<Condition>,
+}
+
+#[derive(Clone, Debug)]
+enum Condition {
+    Any,
+    Letter(char),
+    OneOf(Vec<char>),
+    NoneOf(Vec<char>),
+}
+
+impl Affixes {
+    pub fn new(aff_file: &str) -> Affixes {
+        let mut result = Affixes::default();
+
+        for line in aff_file.lines() {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            if parts.len() < 4 {
+                continue;
+            }
+
+            let classes = match parts[0] {
+                "PFX" => &mut result.prefixes,
+                "SFX" => &mut result.suffixes,
+                _ => continue,
+            };
+
+            let mut flag_it = parts[1].chars();
+            let flag: char = match (flag_it.next(), flag_it.next()) {
+                (Some(flag), None) => flag,
+                _ => continue,
+            };
+
+            // the header of a class is `SFX flag cross_product count`, rules have at least five parts
+            if parts.len() == 4 && (parts[2] == "Y" || parts[2] == "N") {
+                classes.insert(
+                    flag,
+                    AffixClass {
+                        cross_product: parts[2] == "Y",
+                        rules: Vec::new(),
+                    },
+                );
+                continue;
+            }
+
+            if let Some(class) = classes.get_mut(&flag) {
+                class.rules.push(AffixRule {
+                    strip: Affixes::parse_affix(parts[2]),
+                    add: Affixes::parse_affix(parts[3]),
+                    condition: Affixes::parse_condition(parts.get(4).unwrap_or(&".")),
+                });
+            }
+        }
+
+        result
+    }
+
+    // Returns the word together with all forms its flags produce.
+    pub fn expand(&self, word: &str, flags: &str) -> Vec<String> {
+        let mut result: Vec<String> = vec![word.to_string()];
+
+        let mut cross_suffixed: Vec<String> = Vec::new();
+        for flag in flags.chars() {
+            if let Some(class) = self.suffixes.get(&flag) {
+                for rule in &class.rules {
+                    if let Some(form) = rule.apply_suffix(word) {
+                        if class.cross_product {
+                            cross_suffixed.push(form.clone());
+                        }
+                        result.push(form);
+                    }
+                }
+            }
+        }
+
+        for flag in flags.chars() {
+            if let Some(class) = self.prefixes.get(&flag) {
+                for rule in &class.rules {
+                    if let Some(form) = rule.apply_prefix(word) {
+                        result.push(form);
+                    }
+
+                    if !class.cross_product {
+                        continue;
+                    }
+
+                    for suffixed in &cross_suffixed {
+                        if let Some(form) = rule.apply_prefix(suffixed) {
+                            result.push(form);
+                        }
+                    }
+                }
+            }
+        }
+
+        result.sort();
+        result.dedup();
+        result
+    }
+
+    // `0` stands for an empty affix, continuation flags after a slash are ignored
+    fn parse_affix(text: &str) -> String {
+        let affix: &str = text.split('/').next().unwrap_or("");
+        if affix == "0" {
+            String::new()
+        } else {
+            affix.to_string()
+        }
+    }
+
+    fn parse_condition(text: &str) -> Vec<Condition> {
+        let mut result: Vec<Condition> = Vec::new();
+        let mut char_it = text.chars();
+
+        while let Some(c) = char_it.next() {
+            match c {
+                '.' => result.push(Condition::Any),
+                '[' => {
+                    let mut letters: Vec<char> = Vec::new();
+                    let mut negated = false;
+                    for letter in char_it.by_ref() {
+                        match letter {
+                            ']' => break,
+                            '^' if letters.is_empty() && !negated => negated = true,
+                            _ => letters.push(letter),
+                        }
+                    }
+
+                    if negated {
+                        result.push(Condition::NoneOf(letters));
+                    } else {
+                        result.push(Condition::OneOf(letters));
+                    }
+                }
+                _ => result.push(Condition::Letter(c)),
+            }
+        }
+
+        result
+    }
+}
+
+impl Condition {
+    fn matches(&self, letter: char) -> bool {
+        match self {
+            Condition::Any => true,
+            Condition::Letter(c) => *c == letter,
+            Condition::OneOf(letters) => letters.contains(&letter),
+            Condition::NoneOf(letters) => !letters.contains(&letter),
+        }
+    }
+}
+
+impl AffixRule {
+    fn apply_suffix(&self, word: &str) -> Option<String> {
+        let letters: Vec<char> = word.chars().collect();
+        if letters.len() < self.condition.len() || !word.ends_with(&self.strip) {
+            return None;
+        }
+
+        let end = &letters[letters.len() - self.condition.len()..];
+        if !self.condition.iter().zip(end).all(|(c, l)| c.matches(*l)) {
+            return None;
+        }
+
+        let stem: &str = &word[..word.len() - self.strip.len()];
+        if stem.is_empty() {
+            return None;
+        }
+
+        Some(format!("{}{}", stem, self.add))
+    }
+
+    fn apply_prefix(&self, word: &str) -> Option<String> {
+        let letters: Vec<char> = word.chars().collect();
+        if letters.len() < self.condition.len() || !word.starts_with(&self.strip) {
+            return None;
+        }
+
+        let start = &letters[..self.condition.len()];
+        if !self.condition.iter().zip(start).all(|(c, l)| c.matches(*l)) {
+            return None;
+        }
+
+        let stem: &str = &word[self.strip.len()..];
+        if stem.is_empty() {
+            return None;
+        }
+
+        Some(format!("{}{}", self.add, stem))
+    }
+}
@@ -0,0 +1,63 @@
+use super::Affixes;
+
+const AFF_FILE: &str = "SET UTF-8
+TRY áéíóúýčďěňřšťžů
+
+PFX N Y 1
+PFX N 0 ne .
+
+SFX A Y 3
+SFX A a y [^k]a
+SFX A ka ce ka
+SFX A a ou a
+
+SFX B N 2
+SFX B 0 u d
+SFX B ec ce ec
+";
+
+#[test]
+fn expand_suffixes() {
+    let affixes = Affixes::new(AFF_FILE);
+
+    assert_eq!(affixes.expand("žena", "A"), vec!["žena", "ženou", "ženy"]);
+    assert_eq!(
+        affixes.expand("matka", "A"),
+        vec!["matce", "matka", "matkou"]
+    );
+    assert_eq!(affixes.expand("hrad", "B"), vec!["hrad", "hradu"]);
+    assert_eq!(affixes.expand("konec", "B"), vec!["konce", "konec"]);
+}
+
+#[test]
+fn expand_prefixes_and_cross_product() {
+    let affixes = Affixes::new(AFF_FILE);
+
+    assert_eq!(affixes.expand("moc", "N"), vec!["moc", "nemoc"]);
+    assert_eq!(
+        affixes.expand("moucha", "AN"),
+        vec![
+            "moucha",
+            "mouchou",
+            "mouchy",
+            "nemoucha",
+            "nemouchou",
+            "nemouchy"
+        ]
+    );
+
+    // class B is not a cross product, so no prefix is applied to its forms
+    assert_eq!(
+        affixes.expand("hrad", "BN"),
+        vec!["hrad", "hradu", "nehrad"]
+    );
+}
+
+#[test]
+fn expand_without_rules() {
+    let affixes = Affixes::new(AFF_FILE);
+
+    assert_eq!(affixes.expand("pivo", ""), vec!["pivo"]);
+    assert_eq!(affixes.expand("pivo", "XYZ"), vec!["pivo"]);
+    assert_eq!(Affixes::new("").expand("pivo", "A"), vec!["pivo"]);
+}
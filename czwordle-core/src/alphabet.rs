@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests;
+
+const CZECH_ALPHABET: &str = "a á=a b c č=c d ď=d e é=e ě=e
+f g h i í=i j k l m n
+ň=n o ó=o p q r ř=r s š=s t
+ť=t u ú=u ů=u v w x y ý=y z ž=z";
+
+// Letters of the language in keyboard rows, any typed letter is mapped to its uppercase form.
+// Letters with diacritics can be folded to their base letter for the easy mode.
+#[derive(Clone, Debug)]
+pub struct Alphabet {
+    rows: Vec<Vec<char>>,
+    uppercase: HashMap<char, char>,
+    lowercase: HashMap<char, char>,
+    folding: HashMap<char, char>,
+}
+
+impl Default for Alphabet {
+    fn default() -> Alphabet {
+        Alphabet::new(CZECH_ALPHABET)
+    }
+}
+
+impl Alphabet {
+    // Every line is one row of letters separated by whitespace, lines starting with `#` are comments.
+    // A letter is written in lowercase, optionally followed by its uppercase form, e.g. `iİ`.
+    // The base letter it folds to may follow after `=`, e.g. `č=c`.
+    pub fn new(text_file: &str) -> Alphabet {
+        let mut result = Alphabet {
+            rows: Vec::new(),
+            uppercase: HashMap::new(),
+            lowercase: HashMap::new(),
+            folding: HashMap::new(),
+        };
+        let mut base_letters: Vec<(char, char)> = Vec::new();
+
+        for line in text_file.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut row: Vec<char> = Vec::new();
+            for token in line.split_whitespace() {
+                let (letter, base) = match token.split_once('=') {
+                    Some((letter, base)) => (letter, base.chars().next()),
+                    None => (token, None),
+                };
+                if letter.is_empty() {
+                    continue;
+                }
+
+                let mut chars = letter.chars();
+                let lowercase: char = chars.next().unwrap();
+                let uppercase: char = chars.next().unwrap_or_else(|| {
+                    let mut upper = lowercase.to_uppercase();
+                    match (upper.next(), upper.next()) {
+                        (Some(c), None) => c,
+                        _ => lowercase,
+                    }
+                });
+
+                result.uppercase.insert(lowercase, uppercase);
+                result.uppercase.insert(uppercase, uppercase);
+                result.lowercase.insert(uppercase, lowercase);
+                row.push(uppercase);
+                if let Some(base) = base {
+                    base_letters.push((uppercase, base));
+                }
+            }
+            result.rows.push(row);
+        }
+
+        for (letter, base) in base_letters {
+            let base: char = result.to_uppercase(base).unwrap_or(base);
+            result.folding.insert(letter, base);
+        }
+
+        result
+    }
+
+    // Returns `None` for characters outside of the alphabet.
+    pub fn to_uppercase(&self, c: char) -> Option<char> {
+        self.uppercase.get(&c).copied()
+    }
+
+    // Words are compared in their uppercase form, characters outside of the alphabet follow the Unicode rules.
+    pub fn to_uppercase_word(&self, word: &str) -> String {
+        let mut result = String::new();
+        for c in word.chars() {
+            match self.to_uppercase(c) {
+                Some(upper) => result.push(upper),
+                None => result.extend(c.to_uppercase()),
+            }
+        }
+        result
+    }
+
+    // The inverse of `to_uppercase_word`, e.g. for the affix rules written in lowercase.
+    pub fn to_lowercase_word(&self, word: &str) -> String {
+        let mut result = String::new();
+        for c in word.chars() {
+            match self.lowercase.get(&c) {
+                Some(lower) => result.push(*lower),
+                None => result.extend(c.to_lowercase()),
+            }
+        }
+        result
+    }
+
+    // Uppercase letter without its diacritics, letters without a base letter stay the same.
+    pub fn fold(&self, c: char) -> char {
+        self.folding.get(&c).copied().unwrap_or(c)
+    }
+
+    pub fn fold_word(&self, word: &str) -> String {
+        word.chars().map(|c| self.fold(c)).collect()
+    }
+
+    pub fn contains(&self, c: char) -> bool {
+        self.uppercase.contains_key(&c)
+    }
+
+    pub fn get_rows(&self) -> &Vec<Vec<char>> {
+        &self.rows
+    }
+}
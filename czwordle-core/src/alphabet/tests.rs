@@ -0,0 +1,63 @@
+use super::Alphabet;
+
+#[test]
+fn alphabet_creation() {
+    let alphabet = Alphabet::new("# comment\na ä b\n\niİ ı\n");
+
+    assert_eq!(
+        alphabet.get_rows(),
+        &vec![vec!['A', 'Ä', 'B'], vec!['İ', 'I']]
+    );
+
+    assert_eq!(alphabet.to_uppercase('ä'), Some('Ä'));
+    assert_eq!(alphabet.to_uppercase('Ä'), Some('Ä'));
+    assert_eq!(alphabet.to_uppercase('i'), Some('İ'));
+    assert_eq!(alphabet.to_uppercase('ı'), Some('I'));
+    assert_eq!(alphabet.to_uppercase('c'), None);
+    assert_eq!(alphabet.to_uppercase('\n'), None);
+    assert!(alphabet.contains('B'));
+    assert!(!alphabet.contains('C'));
+}
+
+#[test]
+fn alphabet_words() {
+    let alphabet = Alphabet::new("k l\niİ ı\n");
+
+    // the letters of the alphabet keep their own pairs, other characters follow Unicode
+    assert_eq!(alphabet.to_uppercase_word("kil"), "KİL");
+    assert_eq!(alphabet.to_uppercase_word("kıl"), "KIL");
+    assert_eq!(alphabet.to_uppercase_word("öz"), "ÖZ");
+    assert_eq!(alphabet.to_lowercase_word("KİL"), "kil");
+    assert_eq!(alphabet.to_lowercase_word("KIL"), "kıl");
+    assert_eq!(alphabet.to_lowercase_word("ÖZ"), "öz");
+}
+
+#[test]
+fn alphabet_folding() {
+    let alphabet = Alphabet::new("a á=a ä=A b\nů=ú ú=u u\n=x y=\n");
+
+    assert_eq!(
+        alphabet.get_rows(),
+        &vec![vec!['A', 'Á', 'Ä', 'B'], vec!['Ů', 'Ú', 'U'], vec!['Y']]
+    );
+    assert_eq!(alphabet.fold('Á'), 'A');
+    assert_eq!(alphabet.fold('Ä'), 'A');
+    assert_eq!(alphabet.fold('Ů'), 'Ú');
+    assert_eq!(alphabet.fold('Ú'), 'U');
+    assert_eq!(alphabet.fold('B'), 'B');
+    assert_eq!(alphabet.fold('Y'), 'Y');
+    assert_eq!(alphabet.fold_word("ÁBÄ"), "ABA");
+}
+
+#[test]
+fn default_alphabet_is_czech() {
+    let alphabet = Alphabet::default();
+
+    for c in "aábcčdďeéěfghiíjklmnňoópqrřsštťuúůvwxyýzž".chars() {
+        assert!(alphabet.contains(c));
+    }
+    assert_eq!(alphabet.get_rows().iter().flatten().count(), 41);
+    assert_eq!(alphabet.to_uppercase('ů'), Some('Ů'));
+    assert!(!alphabet.contains('ä'));
+    assert_eq!(alphabet.fold_word("ŠŮŇÉŘÍČ"), "SUNERIC");
+}
@@ -0,0 +1,177 @@
+use crate::alphabet::Alphabet;
+use crate::dictionary::Dictionary;
+use crate::dictionary::WordList;
+use crate::game::make_share_grid;
+use crate::game::Game;
+use crate::game::GameState;
+use crate::game::GuessError;
+use crate::random::Random;
+
+#[cfg(test)]
+mod tests;
+
+// Tries to pick different words for the boards this many times before allowing a repeated one.
+const MAXIMUM_REPICKS: u32 = 100;
+
+// Several games solved at once, every guess goes to all unsolved boards and the tries are shared.
+// All boards have the same word length and maximum tries. With the hard mode on, a guess must keep the rules of every unsolved board.
+pub struct Boards<'d> {
+    games: Vec<Game<'d>>,
+}
+
+impl<'d> Boards<'d> {
+    pub fn new(games: Vec<Game<'d>>) -> Boards<'d> {
+        assert!(!games.is_empty());
+        Boards { games }
+    }
+
+    // The shared tries grow with the board count, 6 tries on one board give 7 for two, 9 for four and 13 for eight.
+    pub fn new_random(
+        board_count: u32,
+        attempts: u32,
+        word_list: WordList<'d>,
+        random: &mut dyn Random,
+    ) -> Boards<'d> {
+        let maximum_tries: u32 = attempts + board_count - 1;
+        let mut games: Vec<Game<'d>> = Vec::new();
+        let mut repicks: u32 = 0;
+
+        while games.len() < board_count as usize {
+            let game = Game::new(maximum_tries, word_list, random);
+            let is_repeated = games
+                .iter()
+                .any(|other| other.get_correct_word() == game.get_correct_word());
+            if is_repeated && repicks < MAXIMUM_REPICKS {
+                repicks += 1;
+                continue;
+            }
+
+            games.push(game);
+        }
+
+        Boards::new(games)
+    }
+
+    // Boards saved by `to_text`, one game after another separated by an empty line.
+    pub fn from_text(text: &str, dictionary: &'d Dictionary) -> Option<Boards<'d>> {
+        let games: Vec<Game<'d>> = text
+            .split("\n\n")
+            .filter(|board| !board.trim().is_empty())
+            .map(|board| Game::from_text(board, dictionary))
+            .collect::<Option<Vec<Game<'d>>>>()?;
+
+        let first = games.first()?;
+        if games.iter().any(|game| {
+            game.get_word_length() != first.get_word_length()
+                || game.get_maximum_tries() != first.get_maximum_tries()
+        }) {
+            return None;
+        }
+
+        Some(Boards::new(games))
+    }
+
+    pub fn to_text(&self) -> String {
+        self.games
+            .iter()
+            .map(|game| game.to_text())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    // The guess is checked by all unsolved boards before any of them plays it, so a rejected guess changes nothing.
+    pub fn submit_guess(&mut self, guessed_word: &str) -> Result<(), GuessError> {
+        for game in &self.games {
+            if matches!(game.get_game_state(), GameState::Ongoing(_)) {
+                game.check_guess(guessed_word)?;
+            }
+        }
+
+        for game in &mut self.games {
+            if matches!(game.get_game_state(), GameState::Ongoing(_)) {
+                game.submit_guess(guessed_word)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn set_hard_mode(&mut self, hard_mode: bool) {
+        for game in &mut self.games {
+            game.set_hard_mode(hard_mode);
+        }
+    }
+
+    pub fn set_easy_mode(&mut self, easy_mode: bool) {
+        for game in &mut self.games {
+            game.set_easy_mode(easy_mode);
+        }
+    }
+
+    pub fn get_game_state(&self) -> GameState {
+        let guess_count: u32 = self
+            .games
+            .iter()
+            .map(|game| game.get_guesses().len() as u32)
+            .max()
+            .unwrap_or(0);
+
+        let states: Vec<GameState> = self
+            .games
+            .iter()
+            .map(|game| game.get_game_state())
+            .collect();
+        if states
+            .iter()
+            .all(|state| matches!(state, GameState::Win(_)))
+        {
+            GameState::Win(guess_count)
+        } else if states.contains(&GameState::Lose) {
+            GameState::Lose
+        } else {
+            GameState::Ongoing(guess_count)
+        }
+    }
+
+    pub fn get_games(&self) -> &Vec<Game<'d>> {
+        &self.games
+    }
+
+    pub fn get_board_count(&self) -> u32 {
+        self.games.len() as u32
+    }
+
+    pub fn get_word_length(&self) -> u32 {
+        self.games[0].get_word_length()
+    }
+
+    pub fn get_maximum_tries(&self) -> u32 {
+        self.games[0].get_maximum_tries()
+    }
+
+    pub fn is_absurdle(&self) -> bool {
+        self.games[0].is_absurdle()
+    }
+
+    pub fn get_puzzle_number(&self) -> Option<u32> {
+        self.games[0].get_puzzle_number()
+    }
+
+    pub fn get_alphabet(&self) -> &'d Alphabet {
+        self.games[0].get_alphabet()
+    }
+
+    pub fn make_share_grid(&self) -> String {
+        self.games
+            .iter()
+            .map(|game| {
+                make_share_grid(
+                    game.get_puzzle_number(),
+                    game.get_guesses(),
+                    game.get_maximum_tries(),
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n\n")
+    }
+}
@@ -0,0 +1,129 @@
+use crate::dictionary::Dictionary;
+use crate::game::Game;
+use crate::game::GameState;
+use crate::game::GuessError;
+use crate::random::Counter;
+
+use super::Boards;
+
+fn make_boards<'d>(d: &'d Dictionary, words: &[&str], maximum_tries: u32) -> Boards<'d> {
+    let games: Vec<Game> = words
+        .iter()
+        .map(|word| Game::new_with_answer(maximum_tries, word, d.get_word_list(5).unwrap()))
+        .collect();
+    Boards::new(games)
+}
+
+#[test]
+fn boards_creation() {
+    let d = Dictionary::new("kočka\nšátek\nkůlna\npivko", "");
+    let boards = Boards::new_random(4, 6, d.get_word_list(5).unwrap(), &mut Counter::default());
+
+    assert_eq!(boards.get_board_count(), 4);
+    assert_eq!(boards.get_maximum_tries(), 9);
+    assert_eq!(boards.get_word_length(), 5);
+
+    // the dictionary has enough words for all boards to differ
+    let mut words: Vec<String> = boards
+        .get_games()
+        .iter()
+        .map(|game| game.get_correct_word())
+        .collect();
+    words.sort();
+    words.dedup();
+    assert_eq!(words.len(), 4);
+
+    let d = Dictionary::new("kočka", "");
+    let boards = Boards::new_random(2, 6, d.get_word_list(5).unwrap(), &mut Counter::default());
+    assert_eq!(boards.get_board_count(), 2);
+    assert_eq!(boards.get_maximum_tries(), 7);
+}
+
+#[test]
+fn submit_guess_to_all_boards() {
+    let d = Dictionary::new("kočka\nšátek\nkůlna", "");
+    let mut boards = make_boards(&d, &["kočka", "šátek"], 3);
+
+    assert_eq!(
+        boards.submit_guess("abcde").err().unwrap(),
+        GuessError::NotInDictionary
+    );
+    assert!(boards.get_game_state() == GameState::Ongoing(0));
+
+    boards.submit_guess("kočka").unwrap();
+    assert!(boards.get_game_state() == GameState::Ongoing(1));
+    assert!(boards.get_games()[0].get_game_state() == GameState::Win(1));
+
+    // solved boards do not take more guesses
+    boards.submit_guess("kůlna").unwrap();
+    assert_eq!(boards.get_games()[0].get_guesses().len(), 1);
+    assert_eq!(boards.get_games()[1].get_guesses().len(), 2);
+
+    boards.submit_guess("šátek").unwrap();
+    assert!(boards.get_game_state() == GameState::Win(3));
+}
+
+#[test]
+fn rejected_guess_changes_no_board() {
+    let d = Dictionary::new("pivko\nšátek\nšálek\nkočka", "");
+    let mut boards = make_boards(&d, &["pivko", "šátek"], 7);
+    boards.set_hard_mode(true);
+    boards.submit_guess("šálek").unwrap();
+
+    // the first board accepts KOČKA, the second one needs the green Š
+    assert!(matches!(
+        boards.submit_guess("kočka").err().unwrap(),
+        GuessError::BreaksHardMode(_)
+    ));
+    assert_eq!(boards.get_games()[0].get_guesses().len(), 1);
+    assert_eq!(boards.get_games()[1].get_guesses().len(), 1);
+    assert!(boards.get_game_state() == GameState::Ongoing(1));
+}
+
+#[test]
+fn boards_lose_together() {
+    let d = Dictionary::new("kočka\nšátek\nkůlna", "");
+    let mut boards = make_boards(&d, &["kočka", "šátek"], 2);
+
+    boards.submit_guess("kočka").unwrap();
+    boards.submit_guess("kůlna").unwrap();
+    assert!(boards.get_game_state() == GameState::Lose);
+}
+
+#[test]
+fn boards_to_text_and_back() {
+    let d = Dictionary::new("kočka\nšátek\nkůlna", "");
+    let mut boards = make_boards(&d, &["kočka", "šátek"], 7);
+    boards.submit_guess("kůlna").unwrap();
+
+    let text = boards.to_text();
+    let restored = Boards::from_text(&text, &d).unwrap();
+    assert_eq!(restored.get_board_count(), 2);
+    assert_eq!(restored.get_games()[1].get_correct_word(), "ŠÁTEK");
+    assert_eq!(restored.get_games()[1].get_guesses().len(), 1);
+    assert_eq!(restored.to_text(), text);
+
+    // a single saved game is one board
+    let single = make_boards(&d, &["kůlna"], 6);
+    assert_eq!(
+        Boards::from_text(&single.get_games()[0].to_text(), &d)
+            .unwrap()
+            .get_board_count(),
+        1
+    );
+
+    assert!(Boards::from_text("", &d).is_none());
+    assert!(Boards::from_text("6 0 0 - KOČKA\n- - -\n\n7 0 0 - ŠÁTEK\n- - -\n", &d).is_none());
+}
+
+#[test]
+fn boards_share_grid() {
+    let d = Dictionary::new("kočka\nšátek\nkůlna", "");
+    let mut boards = make_boards(&d, &["kočka", "šátek"], 7);
+    boards.submit_guess("kočka").unwrap();
+
+    assert_eq!(
+        boards.make_share_grid(),
+        "čwordle 1/7\n🟩🟩🟩🟩🟩\n\nčwordle X/7\n🟨⬜⬜⬜⬜"
+    );
+}
@@ -0,0 +1,65 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use crate::dictionary::WordList;
+use crate::game::Score;
+
+#[cfg(test)]
+mod tests;
+
+// The answers still possible after the guesses so far, in the order of the word list.
+#[derive(Clone, Debug)]
+pub struct Candidates {
+    words: Vec<String>,
+}
+
+impl Candidates {
+    pub fn new(word_list: &WordList) -> Candidates {
+        Candidates {
+            words: word_list.get_answers().to_vec(),
+        }
+    }
+
+    pub fn get_words(&self) -> &Vec<String> {
+        &self.words
+    }
+
+    // Keeps the candidates the guess scores the same against as against the answer.
+    pub fn keep_matching(&mut self, score: impl Fn(&str) -> Score, answer_score: &Score) {
+        self.words.retain(|word| score(word) == *answer_score);
+    }
+
+    // Groups the candidates by the score the guess gets against them and keeps the largest group.
+    // Ties go to the group revealing fewer greens, then fewer yellows, then to the one found first.
+    pub fn keep_largest_group(&mut self, score: impl Fn(&str) -> Score) -> Score {
+        let mut group_indices: HashMap<Score, usize> = HashMap::new();
+        let mut groups: Vec<(Score, Vec<String>)> = Vec::new();
+
+        for word in self.words.drain(..) {
+            let word_score: Score = score(&word);
+            match group_indices.get(&word_score) {
+                Some(index) => groups[*index].1.push(word),
+                None => {
+                    group_indices.insert(word_score.clone(), groups.len());
+                    groups.push((word_score, vec![word]));
+                }
+            }
+        }
+
+        // `max_by_key` picks the last of equal groups, the reversal makes it the first one
+        let (largest_score, largest_group) = groups
+            .into_iter()
+            .rev()
+            .max_by_key(|(score, words)| {
+                (
+                    words.len(),
+                    Reverse(score.green_positions.len()),
+                    Reverse(score.yellow_positions.len()),
+                )
+            })
+            .expect("there is always at least one candidate");
+
+        self.words = largest_group;
+        largest_score
+    }
+}
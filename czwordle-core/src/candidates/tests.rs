@@ -0,0 +1,37 @@
+use crate::dictionary::Dictionary;
+use crate::game::score;
+
+use super::Candidates;
+
+#[test]
+fn candidates_creation() {
+    let d = Dictionary::new("pivo\nauto\nkolo", "");
+    let candidates = Candidates::new(&d.get_word_list(4).unwrap());
+
+    assert_eq!(candidates.get_words(), &vec!["PIVO", "AUTO", "KOLO"]);
+}
+
+#[test]
+fn keep_largest_group() {
+    let d = Dictionary::new("pivo\nauto\nkolo\nsova", "");
+    let mut candidates = Candidates::new(&d.get_word_list(4).unwrap());
+
+    let kept = candidates.keep_largest_group(|word| score("PIVO", word));
+
+    assert_eq!(kept.green_positions, vec![3]);
+    assert!(kept.yellow_positions.is_empty());
+    assert_eq!(candidates.get_words(), &vec!["AUTO", "KOLO"]);
+}
+
+#[test]
+fn keep_largest_group_with_tie() {
+    let d = Dictionary::new("auto\npivo", "");
+    let mut candidates = Candidates::new(&d.get_word_list(4).unwrap());
+
+    // AUTO gets a yellow A, PIVO nothing, the group revealing less is kept
+    let kept = candidates.keep_largest_group(|word| score("MAMA", word));
+
+    assert!(kept.green_positions.is_empty());
+    assert!(kept.yellow_positions.is_empty());
+    assert_eq!(candidates.get_words(), &vec!["PIVO"]);
+}
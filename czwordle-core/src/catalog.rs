@@ -0,0 +1,66 @@
+use crate::dictionary::TagFilter;
+
+#[cfg(test)]
+mod tests;
+
+pub const CATALOG_PATH: &str = "data/dictionaries.txt";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DictionaryFile {
+    pub name: String,
+    pub answers_path: String,
+    pub guesses_path: Option<String>,
+    pub affix_path: Option<String>,
+    pub alphabet_path: Option<String>,
+    pub filter: TagFilter,
+}
+
+// Parses the list of dictionaries, one per line in the form
+// `NAME answers.txt [guesses.txt] [rules.aff] [letters.alphabet] [+FLAGS] [-FLAGS]`.
+// Paths are relative to the `data/` directory, lines starting with `#` are comments.
+// Answers are limited to words tagged with one of the `+` flags and none of the `-` flags.
+// With a Hunspell `.aff` file, all forms generated from the tags are accepted as guesses.
+// The `.alphabet` file lists the letters of the language, Czech is used without it.
+pub fn parse_catalog(text_file: &str) -> Vec<DictionaryFile> {
+    let mut result: Vec<DictionaryFile> = Vec::new();
+
+    for line in text_file.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut filter = TagFilter::default();
+        let mut affix_path: Option<String> = None;
+        let mut alphabet_path: Option<String> = None;
+        let mut parts: Vec<&str> = Vec::new();
+        for part in line.split_whitespace() {
+            if part.ends_with(".aff") {
+                affix_path = Some(format!("data/{}", part));
+            } else if part.ends_with(".alphabet") {
+                alphabet_path = Some(format!("data/{}", part));
+            } else if let Some(flags) = part.strip_prefix('+') {
+                filter.include.push_str(flags);
+            } else if let Some(flags) = part.strip_prefix('-') {
+                filter.exclude.push_str(flags);
+            } else {
+                parts.push(part);
+            }
+        }
+
+        if parts.len() < 2 || parts.len() > 3 {
+            continue;
+        }
+
+        result.push(DictionaryFile {
+            name: parts[0].to_uppercase(),
+            answers_path: format!("data/{}", parts[1]),
+            guesses_path: parts.get(2).map(|path| format!("data/{}", path)),
+            affix_path,
+            alphabet_path,
+            filter,
+        });
+    }
+
+    result
+}
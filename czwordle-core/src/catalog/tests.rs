@@ -0,0 +1,46 @@
+use crate::dictionary::TagFilter;
+
+use super::parse_catalog;
+use super::DictionaryFile;
+
+#[test]
+fn catalog_parsing() {
+    let catalog = "# comment\n\ncz dictionary.txt guesses.txt\nhunspell cs_CZ.dic cs_CZ.aff -X\nslova slova.txt slovak.alphabet +NM -X -Y\nbroken\ntoo many parts here\n";
+    let files = parse_catalog(catalog);
+
+    assert_eq!(
+        files,
+        vec![
+            DictionaryFile {
+                name: "CZ".to_string(),
+                answers_path: "data/dictionary.txt".to_string(),
+                guesses_path: Some("data/guesses.txt".to_string()),
+                affix_path: None,
+                alphabet_path: None,
+                filter: TagFilter::default(),
+            },
+            DictionaryFile {
+                name: "HUNSPELL".to_string(),
+                answers_path: "data/cs_CZ.dic".to_string(),
+                guesses_path: None,
+                affix_path: Some("data/cs_CZ.aff".to_string()),
+                alphabet_path: None,
+                filter: TagFilter {
+                    include: String::new(),
+                    exclude: "X".to_string(),
+                },
+            },
+            DictionaryFile {
+                name: "SLOVA".to_string(),
+                answers_path: "data/slova.txt".to_string(),
+                guesses_path: None,
+                affix_path: None,
+                alphabet_path: Some("data/slovak.alphabet".to_string()),
+                filter: TagFilter {
+                    include: "NM".to_string(),
+                    exclude: "XY".to_string(),
+                },
+            },
+        ]
+    );
+}
@@ -0,0 +1,29 @@
+#[cfg(test)]
+mod tests;
+
+pub const WORD_LENGTH: u32 = 5;
+pub const ATTEMPTS: u32 = 6;
+
+// 2022-01-01 00:00:00 UTC, the day of puzzle number 1
+const EPOCH_SECONDS: f64 = 1_640_995_200.0;
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+pub fn puzzle_number(unix_time_seconds: f64) -> u32 {
+    if unix_time_seconds < EPOCH_SECONDS {
+        return 1;
+    }
+
+    ((unix_time_seconds - EPOCH_SECONDS) / SECONDS_PER_DAY) as u32 + 1
+}
+
+pub fn word_index(puzzle_number: u32, word_count: usize) -> usize {
+    assert!(word_count > 0);
+
+    // scramble the puzzle number so that consecutive days do not get neighbouring words
+    let mut hash: u64 = puzzle_number as u64;
+    hash = (hash ^ (hash >> 16)).wrapping_mul(0x45d9f3b);
+    hash = (hash ^ (hash >> 16)).wrapping_mul(0x45d9f3b);
+    hash ^= hash >> 16;
+
+    (hash % word_count as u64) as usize
+}
@@ -0,0 +1,28 @@
+use super::puzzle_number;
+use super::word_index;
+
+#[test]
+fn puzzle_number_from_date() {
+    assert_eq!(puzzle_number(0.0), 1);
+    assert_eq!(puzzle_number(1_640_995_200.0), 1);
+    assert_eq!(puzzle_number(1_641_081_599.0), 1);
+    assert_eq!(puzzle_number(1_641_081_600.0), 2);
+    // 2026-10-18 12:00:00 UTC
+    assert_eq!(puzzle_number(1_792_324_800.0), 1752);
+}
+
+#[test]
+fn word_index_is_deterministic() {
+    for puzzle in 1..100 {
+        assert!(word_index(puzzle, 37) < 37);
+    }
+    assert_eq!(word_index(1, 1), 0);
+
+    // every player gets these words, a change of the scrambling would change everyone's daily word
+    assert_eq!(word_index(1, 1000), 871);
+    assert_eq!(word_index(2, 1000), 992);
+    assert_eq!(word_index(1752, 1000), 834);
+    assert_eq!(word_index(1752, 2315), 274);
+    assert_eq!(word_index(1753, 2315), 1335);
+    assert_eq!(word_index(365, 37), 28);
+}
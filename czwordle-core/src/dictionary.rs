@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::affix::Affixes;
+use crate::alphabet::Alphabet;
+use crate::daily;
+use crate::random::Random;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone)]
+pub struct Dictionary {
+    buckets: HashMap<u32, Bucket>,
+    alphabet: Alphabet,
+}
+
+#[derive(Clone)]
+struct Bucket {
+    answers: Vec<String>,
+    wordlist: Vec<String>,
+    wordset: HashSet<String>,
+    folded_wordset: HashSet<String>,
+    tags: HashMap<String, String>,
+}
+
+// Chooses answers by the corpus tags after the slash, every character of the tags is one flag.
+// Words with at least one `include` flag (or any word when empty) and no `exclude` flag pass.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TagFilter {
+    pub include: String,
+    pub exclude: String,
+}
+
+// All words of one length, the answers are picked from the wordlist, guesses are checked in the wordset.
+#[derive(Clone, Copy)]
+pub struct WordList<'d> {
+    bucket: &'d Bucket,
+    alphabet: &'d Alphabet,
+    word_length: u32,
+}
+
+impl Dictionary {
+    pub fn new(answers_file: &str, guesses_file: &str) -> Dictionary {
+        Dictionary::new_with_alphabet(answers_file, guesses_file, Alphabet::default())
+    }
+
+    // The words are uppercased by the alphabet, the same way as the typed letters.
+    pub fn new_with_alphabet(
+        answers_file: &str,
+        guesses_file: &str,
+        alphabet: Alphabet,
+    ) -> Dictionary {
+        let mut result = Dictionary {
+            buckets: HashMap::new(),
+            alphabet,
+        };
+
+        for (word, tags) in result.parse_words(answers_file) {
+            result.insert_guess(word.clone());
+            let bucket = result.get_bucket_mut(&word);
+            bucket.tags.entry(word.clone()).or_insert(tags);
+            bucket.answers.push(word.clone());
+            bucket.wordlist.push(word);
+        }
+
+        for (word, tags) in result.parse_words(guesses_file) {
+            let bucket = result.get_bucket_mut(&word);
+            bucket.tags.entry(word.clone()).or_insert(tags);
+            result.insert_guess(word);
+        }
+
+        result
+    }
+
+    // Limits the answers to words passing the filter, all words are still accepted as guesses.
+    pub fn set_answer_filter(&mut self, filter: &TagFilter) {
+        for bucket in self.buckets.values_mut() {
+            bucket.wordlist = bucket
+                .answers
+                .iter()
+                .filter(|word| filter.matches(&bucket.tags[*word]))
+                .cloned()
+                .collect();
+        }
+    }
+
+    // Adds every form produced by the affix rules from the tags of each word to the accepted guesses.
+    pub fn add_inflected_forms(&mut self, affixes: &Affixes) {
+        let mut forms: Vec<String> = Vec::new();
+        for bucket in self.buckets.values() {
+            for (word, tags) in &bucket.tags {
+                for form in affixes.expand(&self.alphabet.to_lowercase_word(word), tags) {
+                    if form.chars().all(|c| self.alphabet.contains(c)) {
+                        forms.push(self.alphabet.to_uppercase_word(&form));
+                    }
+                }
+            }
+        }
+
+        for form in forms {
+            self.insert_guess(form);
+        }
+    }
+
+    pub fn get_word_list(&self, word_length: u32) -> Option<WordList<'_>> {
+        let bucket = self.buckets.get(&word_length)?;
+        if bucket.wordlist.is_empty() {
+            return None;
+        }
+
+        Some(WordList {
+            bucket,
+            alphabet: &self.alphabet,
+            word_length,
+        })
+    }
+
+    // Lengths for which there is at least one answer, in ascending order.
+    pub fn get_word_lengths(&self) -> Vec<u32> {
+        let mut result: Vec<u32> = self
+            .buckets
+            .iter()
+            .filter(|(_, bucket)| !bucket.wordlist.is_empty())
+            .map(|(word_length, _)| *word_length)
+            .collect();
+        result.sort_unstable();
+        result
+    }
+
+    // Number of words the answer can be picked from.
+    pub fn get_answer_count(&self, word_length: u32) -> usize {
+        self.buckets
+            .get(&word_length)
+            .map_or(0, |bucket| bucket.wordlist.len())
+    }
+
+    fn insert_guess(&mut self, word: String) {
+        let folded_word: String = self.alphabet.fold_word(&word);
+        let bucket = self.get_bucket_mut(&word);
+        bucket.folded_wordset.insert(folded_word);
+        bucket.wordset.insert(word);
+    }
+
+    fn get_bucket_mut(&mut self, word: &str) -> &mut Bucket {
+        let word_length: u32 = word.chars().count().try_into().unwrap();
+        self.buckets.entry(word_length).or_insert_with(|| Bucket {
+            answers: Vec::new(),
+            wordlist: Vec::new(),
+            wordset: HashSet::new(),
+            folded_wordset: HashSet::new(),
+            tags: HashMap::new(),
+        })
+    }
+
+    // Returns the words with their tags, the part of the line after the slash.
+    fn parse_words(&self, text_file: &str) -> Vec<(String, String)> {
+        let mut result: Vec<(String, String)> = Vec::new();
+
+        for line in text_file.lines() {
+            let mut line_it = line.split('/');
+            let word_it = line_it.next();
+            if let Some(string) = word_it {
+                let string_no_whitespace = string.trim();
+                if string_no_whitespace.is_empty() {
+                    continue;
+                }
+
+                // also skips the word count on the first line of Hunspell `.dic` files
+                if !string_no_whitespace.chars().all(char::is_alphabetic) {
+                    continue;
+                }
+
+                if string_no_whitespace.chars().next().unwrap().is_uppercase() {
+                    continue;
+                }
+
+                // a letter outside of the alphabet could not be typed, such a word could never be guessed
+                if !string_no_whitespace
+                    .chars()
+                    .all(|c| self.alphabet.contains(c))
+                {
+                    continue;
+                }
+
+                let tags: &str = line_it
+                    .next()
+                    .and_then(|tags| tags.split_whitespace().next())
+                    .unwrap_or("");
+                result.push((
+                    self.alphabet.to_uppercase_word(string_no_whitespace),
+                    tags.to_string(),
+                ));
+            }
+        }
+
+        result
+    }
+}
+
+impl TagFilter {
+    pub fn matches(&self, tags: &str) -> bool {
+        if !self.include.is_empty() && !tags.chars().any(|tag| self.include.contains(tag)) {
+            return false;
+        }
+
+        !tags.chars().any(|tag| self.exclude.contains(tag))
+    }
+}
+
+impl<'d> WordList<'d> {
+    pub fn get_random_word(&self, random: &mut dyn Random) -> String {
+        let num: usize = random.gen_index(self.bucket.wordlist.len());
+        self.bucket.wordlist[num].clone()
+    }
+
+    pub fn get_daily_word(&self, puzzle_number: u32) -> String {
+        let num: usize = daily::word_index(puzzle_number, self.bucket.wordlist.len());
+        self.bucket.wordlist[num].clone()
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.bucket
+            .wordset
+            .contains(&self.alphabet.to_uppercase_word(word))
+    }
+
+    // Compares the letters without diacritics, as they are folded by the alphabet.
+    pub fn contains_folded(&self, word: &str) -> bool {
+        self.bucket.folded_wordset.contains(
+            &self
+                .alphabet
+                .fold_word(&self.alphabet.to_uppercase_word(word)),
+        )
+    }
+
+    // The words an answer can be picked from.
+    pub fn get_answers(&self) -> &'d [String] {
+        &self.bucket.wordlist
+    }
+
+    pub fn get_word_length(&self) -> u32 {
+        self.word_length
+    }
+
+    pub fn get_alphabet(&self) -> &'d Alphabet {
+        self.alphabet
+    }
+}
@@ -0,0 +1,242 @@
+use crate::affix::Affixes;
+use crate::alphabet::Alphabet;
+use crate::random::Counter;
+
+use super::Dictionary;
+use super::TagFilter;
+
+#[test]
+fn dictionary_creation() {
+    let word_length = 5;
+
+    let words = "pivo/SHORT\nauto/SHORT\ncivka/OK\nmicha/OK";
+    let d = Dictionary::new(words, "");
+    let w = d.get_word_list(word_length).unwrap();
+    assert_eq!(w.bucket.wordlist.len(), 2);
+    assert_eq!(w.bucket.wordset.len(), 2);
+    assert_eq!(w.get_word_length(), word_length);
+
+    assert_eq!(
+        w.bucket.wordlist.first().unwrap().chars().count(),
+        word_length.try_into().unwrap()
+    );
+}
+
+#[test]
+fn get_random_word() {
+    let words = "pivo/SHORT\nauto/SHORT\ncivka/OK\nmicha/OK";
+    let d = Dictionary::new(words, "");
+    let w = d.get_word_list(5).unwrap();
+
+    let mut random = Counter::default();
+    assert_eq!(w.get_random_word(&mut random), "CIVKA");
+    assert_eq!(w.get_random_word(&mut random), "MICHA");
+    assert_eq!(w.get_random_word(&mut random), "CIVKA");
+}
+
+#[test]
+fn contains() {
+    let words = "pivo/SHORT\nauto/SHORT\ncivka/OK\nmicha/OK";
+    let d = Dictionary::new(words, "");
+    let w = d.get_word_list(5).unwrap();
+
+    assert!(!w.contains("word"));
+    assert!(!w.contains("wordle"));
+    assert!(!w.contains("pivo"));
+
+    assert!(w.contains("civka"));
+    assert!(w.contains("micha"));
+
+    assert!(d.get_word_list(4).unwrap().contains("pivo"));
+}
+
+#[test]
+fn get_daily_word() {
+    let words = "pivo/SHORT\nauto/SHORT\ncivka/OK\nmicha/OK\nmoula\npacka";
+    let d = Dictionary::new(words, "");
+    let w = d.get_word_list(5).unwrap();
+
+    for puzzle_number in 1..50 {
+        let r = w.get_daily_word(puzzle_number);
+        assert_eq!(r, w.get_daily_word(puzzle_number));
+        assert!(w.contains(&r));
+    }
+}
+
+#[test]
+fn dictionary_with_guesses() {
+    let answers = "civka/OK\nmicha/OK\npivo";
+    let guesses = "civka\nkapka/X\nlampa\nauto\nvlaštovka";
+    let d = Dictionary::new(answers, guesses);
+    let w = d.get_word_list(5).unwrap();
+
+    assert_eq!(w.bucket.wordlist, vec!["CIVKA", "MICHA"]);
+    assert_eq!(w.bucket.wordset.len(), 4);
+
+    assert!(w.contains("civka"));
+    assert!(w.contains("kapka"));
+    assert!(w.contains("lampa"));
+    assert!(!w.contains("auto"));
+
+    let mut random = Counter::default();
+    for _ in 0..10 {
+        let r = w.get_random_word(&mut random);
+        assert!(r == "CIVKA" || r == "MICHA");
+    }
+}
+
+#[test]
+fn word_lengths() {
+    let answers = "civka\nmicha\npivo\nkoště/X\nmotovidlo\nPraha";
+    let guesses = "auto\nvlaštovka\nmoře";
+    let d = Dictionary::new(answers, guesses);
+
+    assert_eq!(d.get_word_lengths(), vec![4, 5, 9]);
+    assert_eq!(d.get_answer_count(5), 3);
+    assert_eq!(d.get_answer_count(9), 1);
+    assert_eq!(d.get_answer_count(6), 0);
+    assert_eq!(d.get_word_list(5).unwrap().bucket.wordlist.len(), 3);
+    assert_eq!(d.get_word_list(4).unwrap().bucket.wordlist.len(), 1);
+    assert!(d.get_word_list(4).unwrap().contains("moře"));
+    assert!(d.get_word_list(9).unwrap().contains("vlaštovka"));
+    assert!(d.get_word_list(3).is_none());
+    assert!(d.get_word_list(6).is_none());
+}
+
+#[test]
+fn tag_filter() {
+    let nouns = TagFilter {
+        include: "NM".to_string(),
+        exclude: String::new(),
+    };
+    assert!(nouns.matches("N"));
+    assert!(nouns.matches("QM"));
+    assert!(!nouns.matches("Q"));
+    assert!(!nouns.matches(""));
+
+    let not_plural = TagFilter {
+        include: String::new(),
+        exclude: "P".to_string(),
+    };
+    assert!(not_plural.matches(""));
+    assert!(not_plural.matches("NQ"));
+    assert!(!not_plural.matches("NP"));
+
+    assert!(TagFilter::default().matches(""));
+    assert!(TagFilter::default().matches("XYZ"));
+}
+
+#[test]
+fn set_answer_filter() {
+    let answers = "civka/N\nmicha/NP\nvelký/A\npivo/N\npiva/NP";
+    let guesses = "kapka/N";
+    let mut d = Dictionary::new(answers, guesses);
+
+    d.set_answer_filter(&TagFilter {
+        include: "N".to_string(),
+        exclude: "P".to_string(),
+    });
+
+    let w = d.get_word_list(5).unwrap();
+    assert_eq!(w.bucket.wordlist, vec!["CIVKA"]);
+    assert!(w.contains("micha"));
+    assert!(w.contains("velký"));
+    assert!(w.contains("kapka"));
+    assert_eq!(w.bucket.tags["MICHA"], "NP");
+    assert_eq!(d.get_word_lengths(), vec![4, 5]);
+
+    d.set_answer_filter(&TagFilter {
+        include: "A".to_string(),
+        exclude: String::new(),
+    });
+    assert_eq!(d.get_word_list(5).unwrap().bucket.wordlist, vec!["VELKÝ"]);
+    assert!(d.get_word_list(4).is_none());
+    assert_eq!(d.get_word_lengths(), vec![5]);
+
+    d.set_answer_filter(&TagFilter::default());
+    assert_eq!(d.get_word_list(5).unwrap().bucket.wordlist.len(), 3);
+}
+
+#[test]
+fn add_inflected_forms() {
+    let affixes =
+        Affixes::new("SFX A Y 2\nSFX A a y [^k]a\nSFX A a ou a\n\nPFX N Y 1\nPFX N 0 ne .\n");
+    let answers = "3\nžena/A\nmoucha/AN po:noun\nkoza";
+    let mut d = Dictionary::new(answers, "");
+    d.add_inflected_forms(&affixes);
+
+    let w = d.get_word_list(4).unwrap();
+    assert_eq!(w.bucket.wordlist, vec!["ŽENA", "KOZA"]);
+    assert!(w.contains("ženy"));
+    assert!(!w.contains("kozy"));
+
+    assert!(d.get_word_list(5).is_none());
+    assert!(d.buckets[&5].wordset.contains("ŽENOU"));
+
+    let w = d.get_word_list(6).unwrap();
+    assert_eq!(w.bucket.wordlist, vec!["MOUCHA"]);
+    assert!(w.contains("mouchy"));
+
+    assert!(d.get_word_list(8).is_none());
+    assert!(d.buckets[&8].wordset.contains("NEMOUCHY"));
+    assert!(d.buckets[&9].wordset.contains("NEMOUCHOU"));
+    assert!(!d.buckets.contains_key(&1));
+}
+
+#[test]
+fn blank_lines_are_skipped() {
+    let d = Dictionary::new("moula\n\n  \nkoule\n", "\n/X\npivko/X\n");
+    let w = d.get_word_list(5).unwrap();
+
+    assert_eq!(w.bucket.wordlist, vec!["MOULA", "KOULE"]);
+    assert!(w.contains("pivko"));
+    assert!(d.get_word_list(0).is_none());
+    assert!(!d.buckets.contains_key(&0));
+}
+
+#[test]
+fn contains_folded() {
+    let d = Dictionary::new("kůň\nšála", "žák");
+    let w = d.get_word_list(3).unwrap();
+
+    assert!(w.contains_folded("kun"));
+    assert!(w.contains_folded("KŮŇ"));
+    assert!(w.contains_folded("zak"));
+    assert!(!w.contains("kun"));
+    assert!(!w.contains_folded("kan"));
+
+    let d =
+        Dictionary::new_with_alphabet("kůň\nšála", "žák", Alphabet::new("a á b k n ň ů z ž š l"));
+    let w = d.get_word_list(3).unwrap();
+    assert!(w.contains_folded("kůň"));
+    assert!(!w.contains_folded("kun"));
+}
+
+#[test]
+fn custom_uppercase_forms() {
+    let d = Dictionary::new_with_alphabet("kil\nkıl", "", Alphabet::new("k l\niİ ı"));
+    let w = d.get_word_list(3).unwrap();
+
+    assert_eq!(w.get_answers(), &["KİL".to_string(), "KIL".to_string()]);
+    assert!(w.contains("kil"));
+    assert!(w.contains("KİL"));
+    assert!(w.contains("kıl"));
+    assert!(!w.contains("Kİİ"));
+}
+
+#[test]
+fn words_outside_of_the_alphabet_are_skipped() {
+    let d = Dictionary::new("müsli\nkočka\nšátek", "tütüt\npivko");
+    let w = d.get_word_list(5).unwrap();
+    assert_eq!(w.bucket.wordlist, vec!["KOČKA", "ŠÁTEK"]);
+    assert!(!w.contains("tütüt"));
+    assert!(w.contains("pivko"));
+
+    // Ř is not a Slovak letter
+    let slovak = Alphabet::new(include_str!("../../../data/slovak.alphabet"));
+    let d = Dictionary::new_with_alphabet("řádek\nrádio", "", slovak);
+    assert_eq!(
+        d.get_word_list(5).unwrap().get_answers(),
+        &["RÁDIO".to_string()]
+    );
+}
@@ -0,0 +1,602 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt;
+
+use super::alphabet::Alphabet;
+use super::candidates::Candidates;
+use super::dictionary::Dictionary;
+use super::dictionary::WordList;
+use super::letters::Letters;
+use super::random::Random;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone, Debug)]
+pub struct Guess {
+    pub is_correct: bool,
+    pub word: String,
+    pub yellow_positions: Vec<u32>,
+    pub green_positions: Vec<u32>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GuessError {
+    NotInDictionary,
+    WrongLength(u32),
+    BreaksHardMode(HardModeRule),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum HardModeRule {
+    GreenLetter { position: u32, letter: char },
+    YellowLetter(char),
+}
+
+impl fmt::Display for HardModeRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HardModeRule::GreenLetter { position, letter } => {
+                write!(f, "position {} must be {}", position + 1, letter)
+            }
+            HardModeRule::YellowLetter(letter) => write!(f, "guess must contain {}", letter),
+        }
+    }
+}
+
+// The message shown to the player, the word length tells a short guess from a long one.
+pub fn get_error_message(error: &GuessError, word_length: u32) -> String {
+    match error {
+        GuessError::NotInDictionary => "Slovo není ve slovníku".to_string(),
+        GuessError::WrongLength(length) if *length < word_length => "Příliš krátké".to_string(),
+        GuessError::WrongLength(_) => "Příliš dlouhé".to_string(),
+        GuessError::BreaksHardMode(HardModeRule::GreenLetter { position, letter }) => {
+            format!("{}. písmeno musí být {}", position + 1, letter)
+        }
+        GuessError::BreaksHardMode(HardModeRule::YellowLetter(letter)) => {
+            format!("Slovo musí obsahovat {}", letter)
+        }
+    }
+}
+
+#[derive(PartialEq, Eq)]
+pub enum GameState {
+    Win(u32),
+    Lose,
+    Ongoing(u32),
+}
+
+pub struct Game<'dict> {
+    state: State,
+    word_list: WordList<'dict>,
+    // only in the absurdle mode, the answer is then chosen as late as possible
+    candidates: Option<Candidates>,
+}
+
+struct State {
+    maximum_tries: u32,
+    hard_mode: bool,
+    easy_mode: bool,
+    absurdle: bool,
+    puzzle_number: Option<u32>,
+    word_to_guess: String,
+    guesses: Vec<Guess>,
+    letters: Letters,
+}
+
+impl State {
+    // first line: maximum tries, hard mode, easy mode, puzzle number or `-`, the correct word and absurdle mode,
+    // second line: used, green and yellow letters, then one line per guess with its green and yellow positions
+    fn to_text(&self) -> String {
+        let mut result = format!(
+            "{} {} {} {} {} {}\n",
+            self.maximum_tries,
+            self.hard_mode as u32,
+            self.easy_mode as u32,
+            self.puzzle_number
+                .map_or("-".to_string(), |number| number.to_string()),
+            self.word_to_guess,
+            self.absurdle as u32
+        );
+
+        result.push_str(&format!(
+            "{} {} {}\n",
+            letters_to_text(self.letters.get_used_letters()),
+            letters_to_text(self.letters.get_green_letters()),
+            letters_to_text(self.letters.get_yellow_letters())
+        ));
+
+        for guess in &self.guesses {
+            result.push_str(&format!(
+                "{} {} {}\n",
+                guess.word,
+                positions_to_text(&guess.green_positions),
+                positions_to_text(&guess.yellow_positions)
+            ));
+        }
+
+        result
+    }
+
+    fn from_text(text: &str) -> Option<State> {
+        let mut lines = text.lines();
+
+        let header: Vec<&str> = lines.next()?.split_whitespace().collect();
+        // games saved before the absurdle mode have no sixth field
+        if header.len() != 5 && header.len() != 6 {
+            return None;
+        }
+
+        let mut state = State {
+            maximum_tries: header[0].parse().ok()?,
+            hard_mode: header[1] == "1",
+            easy_mode: header[2] == "1",
+            absurdle: header.get(5) == Some(&"1"),
+            puzzle_number: match header[3] {
+                "-" => None,
+                number => Some(number.parse().ok()?),
+            },
+            word_to_guess: header[4].to_string(),
+            guesses: Vec::new(),
+            letters: Letters::new(),
+        };
+
+        let letters: Vec<&str> = lines.next()?.split_whitespace().collect();
+        if letters.len() != 3 {
+            return None;
+        }
+        text_to_letters(letters[0]).for_each(|c| state.letters.add_used_letter(c));
+        text_to_letters(letters[1]).for_each(|c| state.letters.add_green_letter(c));
+        text_to_letters(letters[2]).for_each(|c| state.letters.add_yellow_letter(c));
+
+        let word_length: usize = state.word_to_guess.chars().count();
+        for line in lines {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            if parts.len() != 3 || parts[0].chars().count() != word_length {
+                return None;
+            }
+
+            let green_positions: Vec<u32> = text_to_positions(parts[1], word_length)?;
+            let yellow_positions: Vec<u32> = text_to_positions(parts[2], word_length)?;
+            if green_positions
+                .iter()
+                .any(|position| yellow_positions.contains(position))
+            {
+                return None;
+            }
+
+            state.guesses.push(Guess {
+                is_correct: green_positions.len() == word_length,
+                word: parts[0].to_string(),
+                yellow_positions,
+                green_positions,
+            });
+        }
+
+        if state.guesses.len() > state.maximum_tries as usize {
+            return None;
+        }
+
+        Some(state)
+    }
+}
+
+fn letters_to_text(letters: &HashSet<char>) -> String {
+    if letters.is_empty() {
+        return "-".to_string();
+    }
+
+    let mut result: Vec<char> = letters.iter().copied().collect();
+    result.sort_unstable();
+    result.into_iter().collect()
+}
+
+fn text_to_letters(text: &str) -> impl Iterator<Item = char> + '_ {
+    text.chars().filter(|c| *c != '-')
+}
+
+fn positions_to_text(positions: &[u32]) -> String {
+    if positions.is_empty() {
+        return "-".to_string();
+    }
+
+    positions
+        .iter()
+        .map(|position| position.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+// Every position must be inside the word and listed only once, a corrupted save is rejected.
+fn text_to_positions(text: &str, word_length: usize) -> Option<Vec<u32>> {
+    if text == "-" {
+        return Some(Vec::new());
+    }
+
+    let mut positions: Vec<u32> = Vec::new();
+    for position in text.split(',') {
+        let position: u32 = position.parse().ok()?;
+        if position as usize >= word_length || positions.contains(&position) {
+            return None;
+        }
+        positions.push(position);
+    }
+
+    Some(positions)
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Score {
+    pub green_positions: Vec<u32>,
+    pub yellow_positions: Vec<u32>,
+}
+
+// Greens are matched first, then every letter of the answer not matched by a green can make one yellow.
+// Both words must have the same number of letters.
+pub fn score(guessed_word: &str, correct_word: &str) -> Score {
+    let correct_letters: Vec<char> = correct_word.chars().collect();
+    let guess_letters: Vec<char> = guessed_word.chars().collect();
+
+    assert!(correct_letters.len() == guess_letters.len());
+
+    let mut result = Score::default();
+
+    // letters of the correct word that were not matched by a green, each can make one yellow
+    let mut unmatched_letters: HashMap<char, u32> = HashMap::new();
+
+    for (i, (correct_char, guess_char)) in (0_u32..).zip(correct_letters.iter().zip(&guess_letters))
+    {
+        if correct_char == guess_char {
+            result.green_positions.push(i);
+        } else {
+            *unmatched_letters.entry(*correct_char).or_insert(0) += 1;
+        }
+    }
+
+    for (i, guess_char) in (0_u32..).zip(&guess_letters) {
+        if result.green_positions.contains(&i) {
+            continue;
+        }
+
+        if let Some(count) = unmatched_letters.get_mut(guess_char) {
+            if *count > 0 {
+                *count -= 1;
+                result.yellow_positions.push(i);
+            }
+        }
+    }
+
+    result
+}
+
+pub fn make_share_grid(
+    puzzle_number: Option<u32>,
+    guesses: &[Guess],
+    maximum_tries: u32,
+) -> String {
+    let mut result = String::from("čwordle");
+
+    if let Some(number) = puzzle_number {
+        result.push_str(&format!(" {}", number));
+    }
+
+    match guesses.last() {
+        Some(guess) if guess.is_correct => {
+            result.push_str(&format!(" {}/{}", guesses.len(), maximum_tries))
+        }
+        _ => result.push_str(&format!(" X/{}", maximum_tries)),
+    }
+
+    for guess in guesses {
+        result.push('\n');
+        for i in 0..guess.word.chars().count() as u32 {
+            if guess.green_positions.contains(&i) {
+                result.push('🟩');
+            } else if guess.yellow_positions.contains(&i) {
+                result.push('🟨');
+            } else {
+                result.push('⬜');
+            }
+        }
+    }
+
+    result
+}
+
+impl<'d> Game<'d> {
+    pub fn new(maximum_tries: u32, word_list: WordList<'d>, random: &mut dyn Random) -> Game<'d> {
+        Game::new_with_answer(maximum_tries, &word_list.get_random_word(random), word_list)
+    }
+
+    // The answer does not have to be in the word list, it only needs to have its length.
+    pub fn new_with_answer(maximum_tries: u32, answer: &str, word_list: WordList<'d>) -> Game<'d> {
+        assert!(answer.chars().count() == word_list.get_word_length() as usize);
+
+        Game {
+            state: State {
+                maximum_tries,
+                hard_mode: false,
+                easy_mode: false,
+                absurdle: false,
+                puzzle_number: None,
+                word_to_guess: word_list.get_alphabet().to_uppercase_word(answer),
+                guesses: Vec::new(),
+                letters: Letters::new(),
+            },
+            word_list,
+            candidates: None,
+        }
+    }
+
+    pub fn new_daily(maximum_tries: u32, puzzle_number: u32, word_list: WordList<'d>) -> Game<'d> {
+        Game {
+            state: State {
+                maximum_tries,
+                hard_mode: false,
+                easy_mode: false,
+                absurdle: false,
+                puzzle_number: Some(puzzle_number),
+                word_to_guess: word_list.get_daily_word(puzzle_number),
+                guesses: Vec::new(),
+                letters: Letters::new(),
+            },
+            word_list,
+            candidates: None,
+        }
+    }
+
+    // No answer is picked, every guess keeps the largest group of the answers that score the same.
+    pub fn new_absurdle(maximum_tries: u32, word_list: WordList<'d>) -> Game<'d> {
+        let candidates = Candidates::new(&word_list);
+
+        Game {
+            state: State {
+                maximum_tries,
+                hard_mode: false,
+                easy_mode: false,
+                absurdle: true,
+                puzzle_number: None,
+                word_to_guess: candidates.get_words()[0].clone(),
+                guesses: Vec::new(),
+                letters: Letters::new(),
+            },
+            word_list,
+            candidates: Some(candidates),
+        }
+    }
+
+    // Restores a game saved by `to_text`, returns `None` when the text is broken
+    // or the dictionary has no words of the saved length.
+    pub fn from_text(text: &str, dictionary: &'d Dictionary) -> Option<Game<'d>> {
+        let state = State::from_text(text)?;
+        let word_length: u32 = state.word_to_guess.chars().count().try_into().ok()?;
+
+        let mut game = Game {
+            state,
+            word_list: dictionary.get_word_list(word_length)?,
+            candidates: None,
+        };
+
+        // the candidates are not saved, replaying the guesses narrows them down the same way again
+        if game.state.absurdle {
+            let mut candidates = Candidates::new(&game.word_list);
+            for guess in &game.state.guesses {
+                candidates.keep_largest_group(|word| game.score(&guess.word, word));
+            }
+            game.candidates = Some(candidates);
+        }
+
+        Some(game)
+    }
+
+    pub fn to_text(&self) -> String {
+        self.state.to_text()
+    }
+
+    pub fn get_game_state(&self) -> GameState {
+        let guess_count: u32 = self.state.guesses.len().try_into().unwrap();
+        let mut was_last_guess_winning = false;
+
+        if guess_count > 0 {
+            was_last_guess_winning = self.state.guesses.last().unwrap().is_correct;
+        }
+
+        if was_last_guess_winning {
+            return GameState::Win(guess_count);
+        }
+
+        let are_guesses_depleted = guess_count == self.state.maximum_tries;
+
+        if are_guesses_depleted {
+            GameState::Lose
+        } else {
+            GameState::Ongoing(guess_count)
+        }
+    }
+
+    pub fn get_correct_word(&self) -> String {
+        // TODO: only return correct word when game is finished
+        self.state.word_to_guess.clone()
+    }
+
+    pub fn submit_guess(&mut self, guessed_word: &str) -> Result<Guess, GuessError> {
+        self.check_guess(guessed_word)?;
+
+        if let Some(mut candidates) = self.candidates.take() {
+            let guessed_word: String = self.get_alphabet().to_uppercase_word(guessed_word);
+            candidates.keep_largest_group(|word| self.score(&guessed_word, word));
+            self.state.word_to_guess = candidates.get_words()[0].clone();
+            self.candidates = Some(candidates);
+        }
+
+        let guess = self.calculate_guess(guessed_word);
+        self.state.guesses.push(guess.clone());
+        Ok(guess)
+    }
+
+    // The same checks as `submit_guess`, without playing the guess.
+    pub fn check_guess(&self, guessed_word: &str) -> Result<(), GuessError> {
+        if guessed_word.chars().count() != self.state.word_to_guess.chars().count() {
+            return Err(GuessError::WrongLength(
+                guessed_word.chars().count().try_into().unwrap(),
+            ));
+        }
+
+        let is_known_word: bool = if self.state.easy_mode {
+            self.word_list.contains_folded(guessed_word)
+        } else {
+            self.word_list.contains(guessed_word)
+        };
+        if !is_known_word {
+            return Err(GuessError::NotInDictionary);
+        }
+
+        if self.state.hard_mode {
+            self.check_hard_mode(guessed_word)
+                .map_err(GuessError::BreaksHardMode)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn get_letters(&self) -> &Letters {
+        &self.state.letters
+    }
+
+    pub fn get_guesses(&self) -> &Vec<Guess> {
+        &self.state.guesses
+    }
+
+    pub fn get_word_length(&self) -> u32 {
+        self.word_list.get_word_length()
+    }
+
+    pub fn get_word_list(&self) -> WordList<'d> {
+        self.word_list
+    }
+
+    pub fn get_maximum_tries(&self) -> u32 {
+        self.state.maximum_tries
+    }
+
+    pub fn get_alphabet(&self) -> &'d Alphabet {
+        self.word_list.get_alphabet()
+    }
+
+    pub fn get_puzzle_number(&self) -> Option<u32> {
+        self.state.puzzle_number
+    }
+
+    pub fn is_absurdle(&self) -> bool {
+        self.state.absurdle
+    }
+
+    pub fn is_easy_mode(&self) -> bool {
+        self.state.easy_mode
+    }
+
+    pub fn set_hard_mode(&mut self, hard_mode: bool) {
+        self.state.hard_mode = hard_mode;
+    }
+
+    // In the easy mode letters match regardless of their diacritics, e.g. Č matches C.
+    pub fn set_easy_mode(&mut self, easy_mode: bool) {
+        self.state.easy_mode = easy_mode;
+    }
+
+    fn fold(&self, letter: char) -> char {
+        if self.state.easy_mode {
+            self.get_alphabet().fold(letter)
+        } else {
+            letter
+        }
+    }
+
+    fn check_hard_mode(&self, guessed_word: &str) -> Result<(), HardModeRule> {
+        let guess_letters: Vec<char> = self
+            .get_alphabet()
+            .to_uppercase_word(guessed_word)
+            .chars()
+            .collect();
+
+        for past_guess in &self.state.guesses {
+            let past_letters: Vec<char> = past_guess.word.chars().collect();
+
+            for position in &past_guess.green_positions {
+                let letter = past_letters[*position as usize];
+                if self.fold(guess_letters[*position as usize]) != self.fold(letter) {
+                    return Err(HardModeRule::GreenLetter {
+                        position: *position,
+                        letter,
+                    });
+                }
+            }
+
+            // every revealed letter has to be present at least as many times as it was revealed
+            let mut revealed_letters: HashMap<char, usize> = HashMap::new();
+            for position in past_guess
+                .green_positions
+                .iter()
+                .chain(&past_guess.yellow_positions)
+            {
+                *revealed_letters
+                    .entry(self.fold(past_letters[*position as usize]))
+                    .or_insert(0) += 1;
+            }
+
+            for position in &past_guess.yellow_positions {
+                let letter = past_letters[*position as usize];
+                let count = guess_letters
+                    .iter()
+                    .filter(|c| self.fold(**c) == self.fold(letter))
+                    .count();
+                if count < revealed_letters[&self.fold(letter)] {
+                    return Err(HardModeRule::YellowLetter(letter));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Green and yellow positions of the guess against the answer, compared by their folded letters.
+    pub fn score(&self, guessed_word: &str, correct_word: &str) -> Score {
+        let fold = |word: &str| -> String { self.fold_letters(word).into_iter().collect() };
+        score(&fold(guessed_word), &fold(correct_word))
+    }
+
+    // The letters of the word as they are compared by `score`.
+    pub(crate) fn fold_letters(&self, word: &str) -> Vec<char> {
+        word.chars().map(|c| self.fold(c)).collect()
+    }
+
+    fn calculate_guess(&mut self, guessed_word: &str) -> Guess {
+        let guessed_word: String = self.get_alphabet().to_uppercase_word(guessed_word);
+        let score: Score = self.score(&guessed_word, &self.state.word_to_guess);
+
+        let correct_letters: Vec<char> = self.state.word_to_guess.chars().collect();
+        let guess_letters: Vec<char> = guessed_word.chars().collect();
+
+        // the green letters show the letters of the correct word, they differ from the guess in the easy mode
+        let mut shown_letters: Vec<char> = guess_letters.clone();
+        for (i, guess_char) in (0_u32..).zip(&guess_letters) {
+            self.state.letters.add_used_letter(*guess_char);
+
+            if score.green_positions.contains(&i) {
+                let correct_char: char = correct_letters[i as usize];
+                self.state.letters.add_green_letter(*guess_char);
+                self.state.letters.add_green_letter(correct_char);
+                shown_letters[i as usize] = correct_char;
+            } else if score.yellow_positions.contains(&i) {
+                self.state.letters.add_yellow_letter(*guess_char);
+            }
+        }
+
+        Guess {
+            is_correct: score.green_positions.len() == correct_letters.len(),
+            word: shown_letters.into_iter().collect(),
+            yellow_positions: score.yellow_positions,
+            green_positions: score.green_positions,
+        }
+    }
+}
@@ -0,0 +1,431 @@
+use crate::alphabet::Alphabet;
+use crate::dictionary::Dictionary;
+use crate::game::GameState;
+use crate::random::Counter;
+use crate::random::SeededRandom;
+
+use super::make_share_grid;
+use super::score;
+use super::Game;
+use super::Guess;
+use super::GuessError;
+use super::HardModeRule;
+
+#[test]
+fn game_creation() {
+    let words = "pivo/SHORT\nauto/SHORT\ncivka/OK\nmicha/OK";
+    let d = Dictionary::new(words, "");
+
+    let game = Game::new(6, d.get_word_list(5).unwrap(), &mut Counter::default());
+
+    assert_eq!(game.state.maximum_tries, 6);
+    assert_eq!(game.state.word_to_guess.chars().count(), 5);
+    assert_eq!(game.state.guesses.len(), 0);
+}
+
+#[test]
+fn game_creation_with_seed() {
+    let words = "pivo\nauto\ncivka\nmicha\nkolo\nlampa\nmotor\nsokol";
+    let d = Dictionary::new(words, "");
+    let word_list = d.get_word_list(5).unwrap();
+
+    let answers = |seed: u64| -> Vec<String> {
+        let mut random = SeededRandom::new(seed);
+        (0..4)
+            .map(|_| Game::new(6, word_list, &mut random).get_correct_word())
+            .collect()
+    };
+
+    // the same seed always plays these answers, another seed plays different ones
+    assert_eq!(answers(7), vec!["LAMPA", "SOKOL", "MICHA", "MOTOR"]);
+    assert_eq!(answers(7), answers(7));
+    assert_eq!(answers(123456789), vec!["LAMPA", "MICHA", "CIVKA", "LAMPA"]);
+    assert_ne!(answers(7), answers(123456789));
+}
+
+#[test]
+fn get_game_state_after_all_guesses_are_depleted() {
+    let words = "pivo/SHORT\nauto/SHORT\ncivka/OK\nmicha/OK";
+    let d = Dictionary::new(words, "");
+    let mut game = Game::new(6, d.get_word_list(5).unwrap(), &mut Counter::default());
+
+    let state = game.get_game_state();
+    assert!(state == GameState::Ongoing(0));
+
+    game.state.guesses.push(Guess {
+        is_correct: false,
+        word: "TEST".to_string(),
+        yellow_positions: Vec::new(),
+        green_positions: Vec::new(),
+    });
+
+    let state = game.get_game_state();
+    assert!(state == GameState::Ongoing(1));
+
+    for _ in 0..5 {
+        game.state.guesses.push(Guess {
+            is_correct: false,
+            word: "TEST".to_string(),
+            yellow_positions: Vec::new(),
+            green_positions: Vec::new(),
+        });
+    }
+
+    let state = game.get_game_state();
+    assert!(state == GameState::Lose);
+}
+
+#[test]
+fn get_game_state_after_correct_guess() {
+    let words = "pivo/SHORT\nauto/SHORT\ncivka/OK\nmicha/OK";
+    let d = Dictionary::new(words, "");
+    let mut game = Game::new(6, d.get_word_list(5).unwrap(), &mut Counter::default());
+
+    let state = game.get_game_state();
+    assert!(state == GameState::Ongoing(0));
+
+    game.state.guesses.push(Guess {
+        is_correct: true,
+        word: game.state.word_to_guess.clone(),
+        yellow_positions: Vec::new(),
+        green_positions: Vec::new(),
+    });
+
+    let state = game.get_game_state();
+    assert!(state == GameState::Win(1));
+}
+
+#[test]
+fn get_correct_word() {
+    let words = "pivo/SHORT\nauto/SHORT\ncivka/OK";
+    let d = Dictionary::new(words, "");
+    let game = Game::new(6, d.get_word_list(5).unwrap(), &mut Counter::default());
+
+    assert_eq!(game.get_correct_word(), "CIVKA");
+}
+
+#[test]
+fn submit_guess_guess_is_correct() {
+    let words = "pivo/SHORT\nauto/SHORT\ncivka/OK";
+    let d = Dictionary::new(words, "");
+    let mut game = Game::new(6, d.get_word_list(5).unwrap(), &mut Counter::default());
+
+    let error_length = game.submit_guess("guessed_word");
+    assert!(error_length.is_err());
+    assert_eq!(error_length.err().unwrap(), GuessError::WrongLength(12));
+
+    let error_not_in_dict = game.submit_guess("abcde");
+    assert!(error_not_in_dict.is_err());
+    assert_eq!(
+        error_not_in_dict.err().unwrap(),
+        GuessError::NotInDictionary
+    );
+
+    let works = game.submit_guess("civka");
+    assert!(works.is_ok());
+    let g = works.unwrap();
+    assert_eq!(g.word, "CIVKA");
+    assert_eq!(g.green_positions.len(), 5);
+    assert!(g.is_correct);
+    assert_eq!(game.state.guesses.len(), 1);
+}
+
+#[test]
+fn submit_guess_with_custom_uppercase() {
+    let d = Dictionary::new_with_alphabet("kil\nkıl", "", Alphabet::new("k l\niİ ı"));
+    let mut game = Game::new_with_answer(6, "kil", d.get_word_list(3).unwrap());
+
+    // the typed letters are uppercased by the alphabet, İ is the uppercase i
+    let guess = game.submit_guess("KIL").unwrap();
+    assert_eq!(guess.green_positions, vec![0, 2]);
+    assert!(game.submit_guess("KİL").unwrap().is_correct);
+}
+
+#[test]
+fn submit_guess_guess_is_not_correct() {
+    let words = "pivo/SHORT\nauto/SHORT\ncivka/OK\nxyzya/OK";
+    let d = Dictionary::new(words, "");
+    let mut game = Game::new(6, d.get_word_list(5).unwrap(), &mut Counter::default());
+    game.state.word_to_guess = "civka".to_uppercase();
+
+    let works = game.submit_guess("xyzya");
+    assert!(works.is_ok());
+    let g = works.unwrap();
+    assert_eq!(g.word, "XYZYA");
+    assert_eq!(g.green_positions.len(), 1); // only 'A' at the end is correect
+    assert!(!g.is_correct);
+}
+
+#[test]
+fn calculate_guess_with_repeated_letters() {
+    // (correct word, guessed word, green positions, yellow positions)
+    let cases: [(&str, &str, Vec<u32>, Vec<u32>); 9] = [
+        ("packa", "pappa", vec![0, 1, 4], vec![]),
+        ("packa", "aaaaa", vec![1, 4], vec![]),
+        ("packa", "akpxx", vec![], vec![0, 1, 2]),
+        ("kolos", "ookoo", vec![1, 3], vec![2]),
+        ("papír", "rpapí", vec![], vec![0, 1, 2, 3, 4]),
+        ("papír", "ppppp", vec![0, 2], vec![]),
+        ("papír", "oppop", vec![2], vec![1]),
+        ("koště", "šštěk", vec![], vec![0, 2, 3, 4]),
+        ("žábra", "áážáa", vec![1, 4], vec![2]),
+    ];
+
+    for (correct, guessed, green, yellow) in cases {
+        let words = format!("{}\n{}", correct, guessed);
+        let d = Dictionary::new(&words, "");
+        let mut game = Game::new(6, d.get_word_list(5).unwrap(), &mut Counter::default());
+        game.state.word_to_guess = correct.to_uppercase();
+
+        let g = game.submit_guess(guessed).unwrap();
+        assert_eq!(
+            g.green_positions, green,
+            "{} guessed as {}",
+            correct, guessed
+        );
+        assert_eq!(
+            g.yellow_positions, yellow,
+            "{} guessed as {}",
+            correct, guessed
+        );
+        assert_eq!(g.is_correct, correct == guessed);
+    }
+}
+
+#[test]
+fn letters_with_repeated_letters() {
+    let words = "packa\npappa\npapír\noppop";
+    let d = Dictionary::new(words, "");
+    let mut game = Game::new(6, d.get_word_list(5).unwrap(), &mut Counter::default());
+    game.state.word_to_guess = "packa".to_uppercase();
+
+    game.submit_guess("pappa").unwrap();
+    let letters = game.get_letters();
+    assert!(letters.get_yellow_letters().is_empty());
+    assert!(letters.get_green_letters().contains(&'P'));
+    assert!(letters.get_green_letters().contains(&'A'));
+    assert_eq!(letters.get_used_letters().len(), 2);
+
+    let mut game = Game::new(6, d.get_word_list(5).unwrap(), &mut Counter::default());
+    game.state.word_to_guess = "papír".to_uppercase();
+
+    game.submit_guess("oppop").unwrap();
+    let letters = game.get_letters();
+    assert!(letters.get_yellow_letters().contains(&'P'));
+    assert!(!letters.get_yellow_letters().contains(&'O'));
+    assert!(letters.get_green_letters().contains(&'P'));
+    assert!(letters.get_used_letters().contains(&'O'));
+}
+
+#[test]
+fn submit_guess_in_hard_mode() {
+    let words = "packa\nkapka\nlapka\npasta\nšapka\nlačka";
+    let d = Dictionary::new(words, "");
+    let mut game = Game::new(6, d.get_word_list(5).unwrap(), &mut Counter::default());
+    game.set_hard_mode(true);
+    game.state.word_to_guess = "packa".to_uppercase();
+
+    let g = game.submit_guess("pasta").unwrap();
+    assert_eq!(g.green_positions, vec![0, 1, 4]);
+
+    assert_eq!(
+        game.submit_guess("šapka").err().unwrap(),
+        GuessError::BreaksHardMode(HardModeRule::GreenLetter {
+            position: 0,
+            letter: 'P'
+        })
+    );
+    assert_eq!(game.state.guesses.len(), 1);
+
+    let g = game.submit_guess("lapka");
+    assert!(g.is_err());
+
+    game.state.guesses.clear();
+    game.submit_guess("kapka").unwrap();
+    assert_eq!(
+        game.submit_guess("lačka").err().unwrap(),
+        GuessError::BreaksHardMode(HardModeRule::YellowLetter('P'))
+    );
+    assert!(game.submit_guess("packa").unwrap().is_correct);
+}
+
+#[test]
+fn submit_guess_in_easy_mode() {
+    let d = Dictionary::new("kočka\nšátek", "");
+    let mut game = Game::new(6, d.get_word_list(5).unwrap(), &mut Counter::default());
+    game.state.word_to_guess = "šátek".to_uppercase();
+
+    assert_eq!(
+        game.submit_guess("satek").err().unwrap(),
+        GuessError::NotInDictionary
+    );
+
+    game.set_easy_mode(true);
+    let g = game.submit_guess("kocka").unwrap();
+    assert_eq!(g.word, "KOCKA");
+    assert_eq!(g.green_positions, vec![]);
+    assert_eq!(g.yellow_positions, vec![0, 4]);
+
+    let g = game.submit_guess("satek").unwrap();
+    assert!(g.is_correct);
+    assert_eq!(g.word, "ŠÁTEK");
+    assert_eq!(g.green_positions, vec![0, 1, 2, 3, 4]);
+    assert!(game.get_letters().get_green_letters().contains(&'Š'));
+    assert!(game.get_letters().get_green_letters().contains(&'S'));
+
+    let mut game = Game::new(6, d.get_word_list(5).unwrap(), &mut Counter::default());
+    game.set_easy_mode(true);
+    game.set_hard_mode(true);
+    game.state.word_to_guess = "kočka".to_uppercase();
+
+    game.submit_guess("šátek").unwrap();
+    assert_eq!(game.submit_guess("kocka").unwrap().word, "KOČKA");
+}
+
+#[test]
+fn hard_mode_rule_message() {
+    let rule = HardModeRule::GreenLetter {
+        position: 2,
+        letter: 'Š',
+    };
+    assert_eq!(rule.to_string(), "position 3 must be Š");
+    assert_eq!(
+        HardModeRule::YellowLetter('Ř').to_string(),
+        "guess must contain Ř"
+    );
+}
+
+#[test]
+fn daily_game_creation() {
+    let words = "pivo/SHORT\nauto/SHORT\ncivka/OK\nmicha/OK";
+    let d = Dictionary::new(words, "");
+
+    let game = Game::new_daily(6, 42, d.get_word_list(5).unwrap());
+    assert_eq!(game.get_puzzle_number(), Some(42));
+    assert_eq!(
+        game.get_correct_word(),
+        d.get_word_list(5).unwrap().get_daily_word(42)
+    );
+    assert_eq!(game.get_maximum_tries(), 6);
+    assert_eq!(game.get_word_length(), 5);
+
+    assert_eq!(
+        Game::new(6, d.get_word_list(5).unwrap(), &mut Counter::default()).get_puzzle_number(),
+        None
+    );
+}
+
+#[test]
+fn share_grid() {
+    let words = "packa\npappa\nkapka";
+    let d = Dictionary::new(words, "");
+    let mut game = Game::new(6, d.get_word_list(5).unwrap(), &mut Counter::default());
+    game.state.word_to_guess = "packa".to_uppercase();
+
+    game.submit_guess("kapka").unwrap();
+    game.submit_guess("pappa").unwrap();
+    game.submit_guess("packa").unwrap();
+
+    assert_eq!(
+        make_share_grid(Some(123), game.get_guesses(), 6),
+        "čwordle 123 3/6\n⬜🟩🟨🟩🟩\n🟩🟩⬜⬜🟩\n🟩🟩🟩🟩🟩"
+    );
+    assert_eq!(
+        make_share_grid(None, &game.get_guesses()[..2], 2),
+        "čwordle X/2\n⬜🟩🟨🟩🟩\n🟩🟩⬜⬜🟩"
+    );
+    assert_eq!(make_share_grid(Some(1), &[], 6), "čwordle 1 X/6");
+}
+
+#[test]
+fn game_to_text_and_back() {
+    let d = Dictionary::new("kočka\nšátek\nkůlna", "");
+    let mut game = Game::new_daily(6, 42, d.get_word_list(5).unwrap());
+    game.set_easy_mode(true);
+    game.state.word_to_guess = "šátek".to_uppercase();
+    game.submit_guess("kocka").unwrap();
+    game.submit_guess("kůlna").unwrap();
+
+    let text = game.to_text();
+    assert_eq!(
+        text,
+        "6 0 1 42 ŠÁTEK 0\nACKLNOŮ - AK\nKOCKA - 0,4\nKŮLNA - 0,4\n"
+    );
+
+    let restored = Game::from_text(&text, &d).unwrap();
+    assert_eq!(restored.state.maximum_tries, 6);
+    assert!(!restored.state.hard_mode);
+    assert!(restored.state.easy_mode);
+    assert_eq!(restored.get_puzzle_number(), Some(42));
+    assert_eq!(restored.get_correct_word(), "ŠÁTEK");
+    assert_eq!(restored.get_guesses().len(), 2);
+    assert_eq!(restored.get_guesses()[0].yellow_positions, vec![0, 4]);
+    assert_eq!(
+        restored.get_letters().get_used_letters(),
+        game.get_letters().get_used_letters()
+    );
+    assert_eq!(
+        restored.get_letters().get_yellow_letters(),
+        game.get_letters().get_yellow_letters()
+    );
+    assert!(restored.get_game_state() == GameState::Ongoing(2));
+    assert_eq!(restored.to_text(), text);
+
+    assert!(Game::from_text("", &d).is_none());
+    assert!(Game::from_text("6 0 1 - ŠÁTEK\n- - -\nKOCKA 0,x -\n", &d).is_none());
+    assert!(Game::from_text("6 0 1 - ŠÁTEKY\n- - -\n", &d).is_none());
+    assert!(Game::from_text("1 0 0 - ŠÁTEK\n- - -\nKOCKA - -\nKOCKA - -\n", &d).is_none());
+
+    // positions outside the word, repeated or both green and yellow
+    assert!(Game::from_text("6 0 0 - ŠÁTEK\n- - -\nKOCKA 9 -\n", &d).is_none());
+    assert!(Game::from_text("6 0 0 - ŠÁTEK\n- - -\nKOCKA - 5\n", &d).is_none());
+    assert!(Game::from_text("6 0 0 - ŠÁTEK\n- - -\nKOCKA 0,0,0,0,0 -\n", &d).is_none());
+    assert!(Game::from_text("6 0 0 - ŠÁTEK\n- - -\nKOCKA - 4,4\n", &d).is_none());
+    assert!(Game::from_text("6 0 0 - ŠÁTEK\n- - -\nKOCKA 4 0,4\n", &d).is_none());
+    assert!(Game::from_text("6 0 0 - ŠÁTEK\n- - -\nKOCKA 4 0\n", &d).is_some());
+}
+
+#[test]
+fn score_with_repeated_letters() {
+    let result = score("ABBA", "BABA");
+    assert_eq!(result.green_positions, vec![2, 3]);
+    assert_eq!(result.yellow_positions, vec![0, 1]);
+
+    // only one A is left for the yellows after the greens
+    let result = score("AAAB", "BAAC");
+    assert_eq!(result.green_positions, vec![1, 2]);
+    assert_eq!(result.yellow_positions, vec![3]);
+
+    assert_eq!(score("KOČKA", "KOČKA").green_positions, vec![0, 1, 2, 3, 4]);
+    assert_eq!(score("KOCKA", "KOČKA").green_positions, vec![0, 1, 3, 4]);
+}
+
+#[test]
+fn absurdle_picks_the_answer_late() {
+    let d = Dictionary::new("pivo\nauto\nkolo\nsova", "");
+    let mut game = Game::new_absurdle(6, d.get_word_list(4).unwrap());
+    assert!(game.is_absurdle());
+
+    // PIVO leaves AUTO and KOLO with the same score, more than any other group
+    let guess: Guess = game.submit_guess("pivo").unwrap();
+    assert!(!guess.is_correct);
+    assert_eq!(guess.green_positions, vec![3]);
+    assert_eq!(game.get_correct_word(), "AUTO");
+
+    let text = game.to_text();
+    assert!(text.starts_with("6 0 0 - AUTO 1\n"));
+    let mut restored = Game::from_text(&text, &d).unwrap();
+    assert!(restored.is_absurdle());
+    assert_eq!(
+        restored.candidates.as_ref().unwrap().get_words(),
+        &vec!["AUTO".to_string(), "KOLO".to_string()]
+    );
+
+    // both groups have one word, the one with fewer greens is kept
+    let guess: Guess = restored.submit_guess("kolo").unwrap();
+    assert!(!guess.is_correct);
+    assert_eq!(restored.get_correct_word(), "AUTO");
+
+    assert!(restored.submit_guess("auto").unwrap().is_correct);
+    assert!(restored.get_game_state() == GameState::Win(3));
+}
@@ -0,0 +1,140 @@
+use crate::alphabet::Alphabet;
+
+#[cfg(test)]
+mod tests;
+
+pub const LAYOUTS_PATH: &str = "data/layouts.txt";
+
+// One key of a keyboard layout, the frontends decide how the keys are shown.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyKind {
+    Letter(char),
+    Enter,
+    Backspace,
+    Hint,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyboardLayout {
+    pub name: String,
+    pub rows: Vec<Vec<KeyKind>>,
+}
+
+impl Default for KeyboardLayout {
+    // QWERTY with the Czech letters below, used when no layouts could be loaded.
+    fn default() -> KeyboardLayout {
+        KeyboardLayout {
+            name: "QWERTY".to_string(),
+            rows: [
+                "qwertyuiop",
+                "asdfghjkl",
+                "ENTER zxcvbnm DEL",
+                "ěščřžýáíé",
+                "ďťňóúů",
+            ]
+            .iter()
+            .map(|row| parse_row(row))
+            .collect(),
+        }
+    }
+}
+
+const EXTRA_ROW_LENGTH: usize = 10;
+
+impl KeyboardLayout {
+    // The alphabet in its own rows, with the action keys around the last row.
+    pub fn from_alphabet(alphabet: &Alphabet) -> KeyboardLayout {
+        let mut rows: Vec<Vec<KeyKind>> = alphabet
+            .get_rows()
+            .iter()
+            .map(|row| row.iter().map(|c| KeyKind::Letter(*c)).collect())
+            .collect();
+
+        if let Some(last_row) = rows.last_mut() {
+            last_row.insert(0, KeyKind::Enter);
+            last_row.push(KeyKind::Backspace);
+        } else {
+            rows.push(vec![KeyKind::Enter, KeyKind::Backspace]);
+        }
+
+        KeyboardLayout {
+            name: "ABC".to_string(),
+            rows,
+        }
+    }
+
+    // Keeps only the letters of the alphabet, the letters missing from the layout are added in extra rows.
+    pub fn fit(&self, alphabet: &Alphabet) -> Vec<Vec<KeyKind>> {
+        let mut result: Vec<Vec<KeyKind>> = self
+            .rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .filter(|kind| match kind {
+                        KeyKind::Letter(c) => alphabet.contains(*c),
+                        _ => true,
+                    })
+                    .copied()
+                    .collect::<Vec<KeyKind>>()
+            })
+            .filter(|row| !row.is_empty())
+            .collect();
+
+        let missing: Vec<KeyKind> = alphabet
+            .get_rows()
+            .iter()
+            .flatten()
+            .map(|c| KeyKind::Letter(*c))
+            .filter(|kind| !self.rows.iter().any(|row| row.contains(kind)))
+            .collect();
+        for row in missing.chunks(EXTRA_ROW_LENGTH) {
+            result.push(row.to_vec());
+        }
+
+        result
+    }
+}
+
+// Parses the keyboard layouts, each starts with `[NAME]` and is followed by its rows of keys.
+// A row is a list of letters, the words `ENTER` and `DEL` place the action keys.
+// Lines starting with `#` are comments, rows before the first name are ignored.
+pub fn parse_layouts(text_file: &str) -> Vec<KeyboardLayout> {
+    let mut result: Vec<KeyboardLayout> = Vec::new();
+
+    for line in text_file.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            result.push(KeyboardLayout {
+                name: name.trim().to_uppercase(),
+                rows: Vec::new(),
+            });
+        } else if let Some(layout) = result.last_mut() {
+            layout.rows.push(parse_row(line));
+        }
+    }
+
+    result.retain(|layout| !layout.rows.is_empty());
+    result
+}
+
+fn parse_row(line: &str) -> Vec<KeyKind> {
+    let mut result: Vec<KeyKind> = Vec::new();
+
+    for part in line.split_whitespace() {
+        match part {
+            "ENTER" => result.push(KeyKind::Enter),
+            "DEL" => result.push(KeyKind::Backspace),
+            _ => result.extend(
+                part.chars()
+                    .flat_map(|c| c.to_uppercase())
+                    .map(KeyKind::Letter),
+            ),
+        }
+    }
+
+    result
+}
@@ -0,0 +1,76 @@
+use crate::alphabet::Alphabet;
+
+use super::parse_layouts;
+use super::KeyKind;
+use super::KeyboardLayout;
+
+#[test]
+fn layout_parsing() {
+    let text = "ignored\n# comment\n\n[qwertz-cz]\něš\nENTER yx DEL\n[EMPTY]\n[ABC]\na b\n";
+    let layouts = parse_layouts(text);
+
+    assert_eq!(
+        layouts,
+        vec![
+            KeyboardLayout {
+                name: "QWERTZ-CZ".to_string(),
+                rows: vec![
+                    vec![KeyKind::Letter('Ě'), KeyKind::Letter('Š')],
+                    vec![
+                        KeyKind::Enter,
+                        KeyKind::Letter('Y'),
+                        KeyKind::Letter('X'),
+                        KeyKind::Backspace
+                    ],
+                ],
+            },
+            KeyboardLayout {
+                name: "ABC".to_string(),
+                rows: vec![vec![KeyKind::Letter('A'), KeyKind::Letter('B')]],
+            },
+        ]
+    );
+}
+
+#[test]
+fn default_layout_has_czech_letters() {
+    let layout = KeyboardLayout::default();
+    let letters: Vec<KeyKind> = layout.rows.into_iter().flatten().collect();
+
+    for c in "QWERTYUIOPASDFGHJKLZXCVBNMĚŠČŘŽÝÁÍÉĎŤŇÓÚŮ".chars() {
+        assert!(letters.contains(&KeyKind::Letter(c)));
+    }
+    assert!(letters.contains(&KeyKind::Enter));
+    assert!(letters.contains(&KeyKind::Backspace));
+}
+
+#[test]
+fn layout_fitted_to_alphabet() {
+    let layout = parse_layouts("[TEST]\nqwe\nasd\nENTER x DEL\n").remove(0);
+    let alphabet = Alphabet::new("a ä b c d e\nf g h i j k\nl ľ m n\n");
+
+    assert_eq!(
+        layout.fit(&alphabet),
+        vec![
+            vec![KeyKind::Letter('E')],
+            vec![KeyKind::Letter('A'), KeyKind::Letter('D')],
+            vec![KeyKind::Enter, KeyKind::Backspace],
+            "ÄBCFGHIJKL".chars().map(KeyKind::Letter).collect(),
+            "ĽMN".chars().map(KeyKind::Letter).collect(),
+        ]
+    );
+}
+
+#[test]
+fn layout_from_alphabet() {
+    let layout = KeyboardLayout::from_alphabet(&Alphabet::new("a b\nc\n"));
+
+    assert_eq!(layout.name, "ABC");
+    assert_eq!(
+        layout.rows,
+        vec![
+            vec![KeyKind::Letter('A'), KeyKind::Letter('B')],
+            vec![KeyKind::Enter, KeyKind::Letter('C'), KeyKind::Backspace],
+        ]
+    );
+}
@@ -6,6 +6,12 @@ pub struct Letters {
     yellow: HashSet<char>,
 }
 
+impl Default for Letters {
+    fn default() -> Letters {
+        Letters::new()
+    }
+}
+
 impl Letters {
     pub fn new() -> Letters {
         Letters {
@@ -0,0 +1,19 @@
+// The rules of the game without any window or platform code, shared by the graphical game,
+// the command-line tools and anything else embedding them. Random choices go through `random::Random`.
+
+pub mod affix;
+pub mod alphabet;
+pub mod boards;
+pub mod candidates;
+pub mod catalog;
+pub mod daily;
+pub mod dictionary;
+pub mod game;
+pub mod layout;
+pub mod letters;
+pub mod random;
+pub mod session;
+pub mod settings;
+pub mod solver;
+pub mod statistics;
+pub mod storage;
@@ -0,0 +1,65 @@
+// Source of the random choices, every frontend brings its own so that the rules do not depend on one.
+pub trait Random {
+    // A number from `0..upper`, `upper` is never 0.
+    fn gen_index(&mut self, upper: usize) -> usize;
+}
+
+#[cfg(test)]
+mod tests;
+
+// `--seed N` picks the same answers again, e.g. to reproduce a bug report.
+// Returns `None` without the option, the frontends then seed by the clock.
+pub fn parse_seed(args: &[String]) -> Result<Option<u64>, String> {
+    match args {
+        [] => Ok(None),
+        [name, value] if name == "--seed" => value
+            .parse()
+            .map(Some)
+            .map_err(|_| format!("--seed needs a number, not {}", value)),
+        [name] if name == "--seed" => Err("--seed needs a value".to_string()),
+        _ => Err(format!("unknown option {}", args[0])),
+    }
+}
+
+// SplitMix64, the same seed always picks the same answers so that a game can be played again.
+pub struct SeededRandom {
+    seed: u64,
+    state: u64,
+}
+
+impl SeededRandom {
+    pub fn new(seed: u64) -> SeededRandom {
+        SeededRandom { seed, state: seed }
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+impl Random for SeededRandom {
+    fn gen_index(&mut self, upper: usize) -> usize {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z: u64 = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        (z % upper as u64) as usize
+    }
+}
+
+// Picks the indices 0, 1, 2... in turn, for tests that need to know the answers.
+#[cfg(test)]
+#[derive(Default)]
+pub struct Counter {
+    next: usize,
+}
+
+#[cfg(test)]
+impl Random for Counter {
+    fn gen_index(&mut self, upper: usize) -> usize {
+        let result = self.next % upper;
+        self.next += 1;
+        result
+    }
+}
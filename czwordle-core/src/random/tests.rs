@@ -0,0 +1,46 @@
+use super::*;
+
+#[test]
+fn same_seed_same_indices() {
+    let mut first = SeededRandom::new(42);
+    let mut second = SeededRandom::new(42);
+    for _ in 0..100 {
+        assert_eq!(first.gen_index(1000), second.gen_index(1000));
+    }
+    assert_eq!(first.get_seed(), 42);
+}
+
+#[test]
+fn different_seeds() {
+    let first: Vec<usize> = {
+        let mut random = SeededRandom::new(1);
+        (0..10).map(|_| random.gen_index(1000)).collect()
+    };
+    let second: Vec<usize> = {
+        let mut random = SeededRandom::new(2);
+        (0..10).map(|_| random.gen_index(1000)).collect()
+    };
+    assert_ne!(first, second);
+}
+
+#[test]
+fn within_bounds() {
+    // zero is a valid seed as well
+    let mut random = SeededRandom::new(0);
+    for upper in 1..200 {
+        assert!(random.gen_index(upper) < upper);
+    }
+    assert_eq!(random.gen_index(1), 0);
+}
+
+#[test]
+fn parse_seed_option() {
+    let args = |text: &str| -> Vec<String> { text.split_whitespace().map(String::from).collect() };
+
+    assert_eq!(parse_seed(&args("")), Ok(None));
+    assert_eq!(parse_seed(&args("--seed 42")), Ok(Some(42)));
+    assert!(parse_seed(&args("--seed")).is_err());
+    assert!(parse_seed(&args("--seed x")).is_err());
+    assert!(parse_seed(&args("--seeds 42")).is_err());
+    assert!(parse_seed(&args("--seed 42 --seed 43")).is_err());
+}
@@ -0,0 +1,148 @@
+// The setup around the games that both frontends share. The frontends load the stored texts,
+// pass in the current time and save what changed, nothing here reads files or the clock.
+
+use std::collections::BTreeMap;
+
+use crate::affix::Affixes;
+use crate::alphabet::Alphabet;
+use crate::boards::Boards;
+use crate::daily;
+use crate::dictionary::Dictionary;
+use crate::dictionary::TagFilter;
+use crate::game::Game;
+use crate::game::GameState;
+use crate::random::Random;
+use crate::settings;
+use crate::settings::Settings;
+use crate::statistics::Statistics;
+
+#[cfg(test)]
+mod tests;
+
+// The texts of one dictionary from the catalog, or of the built-in one.
+#[derive(Clone, Debug, Default)]
+pub struct DictionaryTexts {
+    pub answers: String,
+    pub guesses: String,
+    pub filter: TagFilter,
+    pub affixes: String,
+    pub alphabet: Option<String>,
+}
+
+impl DictionaryTexts {
+    pub fn new(answers: &str, guesses: &str) -> DictionaryTexts {
+        DictionaryTexts {
+            answers: answers.to_string(),
+            guesses: guesses.to_string(),
+            ..DictionaryTexts::default()
+        }
+    }
+
+    pub fn get_alphabet(&self) -> Alphabet {
+        match &self.alphabet {
+            Some(text) => Alphabet::new(text),
+            None => Alphabet::default(),
+        }
+    }
+}
+
+pub fn make_dictionary(texts: &DictionaryTexts) -> Dictionary {
+    let mut dictionary =
+        Dictionary::new_with_alphabet(&texts.answers, &texts.guesses, texts.get_alphabet());
+    dictionary.set_answer_filter(&texts.filter);
+    dictionary.add_inflected_forms(&Affixes::new(&texts.affixes));
+    dictionary
+}
+
+// Answer counts of the word lengths the settings allow, only lengths with some answers are present.
+pub fn get_word_counts(dictionary: &Dictionary) -> BTreeMap<u32, usize> {
+    dictionary
+        .get_word_lengths()
+        .into_iter()
+        .filter(|word_length| settings::WORD_LENGTH_RANGE.contains(word_length))
+        .map(|word_length| (word_length, dictionary.get_answer_count(word_length)))
+        .collect()
+}
+
+// Only a game that can still be played is continued, a finished one is already recorded.
+pub fn load_saved_game<'d>(text: &str, dictionary: &'d Dictionary) -> Option<Boards<'d>> {
+    let boards = Boards::from_text(text, dictionary)?;
+    match boards.get_game_state() {
+        GameState::Ongoing(_) => Some(boards),
+        _ => None,
+    }
+}
+
+// Returns `None` when the dictionary has no words of the chosen length.
+pub fn make_boards<'d>(
+    settings: &Settings,
+    dictionary: &'d Dictionary,
+    daily: bool,
+    unix_time_seconds: f64,
+    random: &mut dyn Random,
+) -> Option<Boards<'d>> {
+    let word_length = if daily {
+        daily::WORD_LENGTH
+    } else {
+        settings.word_length
+    };
+
+    let word_list = dictionary.get_word_list(word_length)?;
+
+    if daily {
+        let puzzle_number = daily::puzzle_number(unix_time_seconds);
+        let mut game = Game::new_daily(daily::ATTEMPTS, puzzle_number, word_list);
+        game.set_hard_mode(settings.hard_mode);
+        game.set_easy_mode(settings.easy_mode);
+        return Some(Boards::new(vec![game]));
+    }
+
+    // the absurdle mode has a single board, all boards would narrow the words the same way
+    let mut boards = if settings.absurdle && settings.boards == 1 {
+        Boards::new(vec![Game::new_absurdle(settings.attempts, word_list)])
+    } else {
+        Boards::new_random(settings.boards, settings.attempts, word_list, random)
+    };
+    boards.set_hard_mode(settings.hard_mode);
+    boards.set_easy_mode(settings.easy_mode);
+    Some(boards)
+}
+
+// The statistics only count the usual games with one board, returns true when they changed.
+pub fn record_game(statistics: &mut Statistics, boards: &Boards) -> bool {
+    let game_state = boards.get_game_state();
+    if matches!(game_state, GameState::Ongoing(_)) || !is_counted(boards) {
+        return false;
+    }
+
+    statistics.add_game(
+        boards.get_word_length(),
+        boards.get_maximum_tries(),
+        &game_state,
+    );
+    true
+}
+
+// A saved game replaced by another one before it is finished counts as lost,
+// so that leaving a bad game does not save the streak. Returns true when the statistics changed.
+pub fn record_abandoned_game(
+    statistics: &mut Statistics,
+    saved_game: &str,
+    dictionary: &Dictionary,
+) -> bool {
+    let boards = match load_saved_game(saved_game, dictionary) {
+        Some(boards) if is_counted(&boards) => boards,
+        _ => return false,
+    };
+
+    statistics.add_game(
+        boards.get_word_length(),
+        boards.get_maximum_tries(),
+        &GameState::Lose,
+    );
+    true
+}
+
+fn is_counted(boards: &Boards) -> bool {
+    boards.get_board_count() == 1 && !boards.is_absurdle()
+}
@@ -0,0 +1,198 @@
+use crate::game::GameState;
+use crate::random::Counter;
+use crate::settings::Settings;
+use crate::statistics::Statistics;
+
+use super::get_word_counts;
+use super::load_saved_game;
+use super::make_boards;
+use super::make_dictionary;
+use super::record_abandoned_game;
+use super::record_game;
+use super::DictionaryTexts;
+
+// 2026-10-18 12:00:00 UTC
+const TIME: f64 = 1_792_324_800.0;
+
+fn make_texts() -> DictionaryTexts {
+    DictionaryTexts {
+        answers: "kočka/N\nšátek/N\nkůlna/N\npivko/N\nvelký/A\nauto/N\nx/N".to_string(),
+        guesses: "lampa".to_string(),
+        ..DictionaryTexts::default()
+    }
+}
+
+#[test]
+fn dictionary_from_texts() {
+    let mut texts = make_texts();
+    texts.filter.include = "N".to_string();
+    let d = make_dictionary(&texts);
+
+    let w = d.get_word_list(5).unwrap();
+    assert_eq!(w.get_answers().len(), 4);
+    assert!(w.contains("velký"));
+    assert!(w.contains("lampa"));
+
+    // Č is not in this alphabet
+    texts.alphabet = Some("a á e é i í k l n o p s š t u ů v y ý".to_string());
+    let d = make_dictionary(&texts);
+    assert_eq!(d.get_word_list(5).unwrap().get_answers().len(), 3);
+}
+
+#[test]
+fn word_counts_skip_lengths_outside_of_the_settings() {
+    let d = make_dictionary(&make_texts());
+    let counts = get_word_counts(&d);
+
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts[&4], 1);
+    assert_eq!(counts[&5], 5);
+}
+
+#[test]
+fn new_and_daily_boards() {
+    let d = make_dictionary(&make_texts());
+    let settings = Settings {
+        boards: 2,
+        ..Settings::default()
+    };
+
+    let boards = make_boards(&settings, &d, false, TIME, &mut Counter::default()).unwrap();
+    assert_eq!(boards.get_board_count(), 2);
+    assert_eq!(boards.get_maximum_tries(), 7);
+    assert_eq!(boards.get_puzzle_number(), None);
+
+    let boards = make_boards(&settings, &d, true, TIME, &mut Counter::default()).unwrap();
+    assert_eq!(boards.get_board_count(), 1);
+    assert_eq!(boards.get_puzzle_number(), Some(1752));
+
+    let settings = Settings {
+        word_length: 7,
+        ..Settings::default()
+    };
+    assert!(make_boards(&settings, &d, false, TIME, &mut Counter::default()).is_none());
+}
+
+#[test]
+fn hard_mode_applies_to_all_boards() {
+    let d = make_dictionary(&make_texts());
+    let mut settings = Settings {
+        boards: 2,
+        hard_mode: true,
+        ..Settings::default()
+    };
+
+    // the answers are KOČKA and ŠÁTEK, the K of KŮLNA is green on the first board
+    let mut boards = make_boards(&settings, &d, false, TIME, &mut Counter::default()).unwrap();
+    boards.submit_guess("kůlna").unwrap();
+    assert!(boards.submit_guess("lampa").is_err());
+
+    settings.hard_mode = false;
+    let mut boards = make_boards(&settings, &d, false, TIME, &mut Counter::default()).unwrap();
+    boards.submit_guess("kůlna").unwrap();
+    assert!(boards.submit_guess("lampa").is_ok());
+}
+
+#[test]
+fn only_ongoing_games_are_loaded() {
+    let d = make_dictionary(&make_texts());
+    let mut boards = make_boards(
+        &Settings::default(),
+        &d,
+        false,
+        TIME,
+        &mut Counter::default(),
+    )
+    .unwrap();
+
+    boards.submit_guess("lampa").unwrap();
+    let text: String = boards.to_text();
+    assert_eq!(load_saved_game(&text, &d).unwrap().to_text(), text);
+
+    boards
+        .submit_guess(&boards.get_games()[0].get_correct_word())
+        .unwrap();
+    assert!(load_saved_game(&boards.to_text(), &d).is_none());
+    assert!(load_saved_game("", &d).is_none());
+}
+
+#[test]
+fn finished_games_with_one_board_are_recorded() {
+    let d = make_dictionary(&make_texts());
+    let mut statistics = Statistics::new();
+    let mut boards = make_boards(
+        &Settings::default(),
+        &d,
+        false,
+        TIME,
+        &mut Counter::default(),
+    )
+    .unwrap();
+
+    assert!(!record_game(&mut statistics, &boards));
+    boards
+        .submit_guess(&boards.get_games()[0].get_correct_word())
+        .unwrap();
+    assert!(boards.get_game_state() == GameState::Win(1));
+    assert!(record_game(&mut statistics, &boards));
+    assert_eq!(statistics.get_record(5, 6).wins, 1);
+
+    let settings = Settings {
+        boards: 2,
+        attempts: 1,
+        ..Settings::default()
+    };
+    let mut boards = make_boards(&settings, &d, false, TIME, &mut Counter::default()).unwrap();
+    boards.submit_guess("lampa").unwrap();
+    boards.submit_guess("lampa").unwrap();
+    assert!(boards.get_game_state() == GameState::Lose);
+    assert!(!record_game(&mut statistics, &boards));
+    assert_eq!(statistics.get_record(5, 2).played, 0);
+}
+
+#[test]
+fn abandoned_games_are_recorded_as_lost() {
+    let d = make_dictionary(&make_texts());
+    let mut statistics = Statistics::new();
+    let mut boards = make_boards(
+        &Settings::default(),
+        &d,
+        false,
+        TIME,
+        &mut Counter::default(),
+    )
+    .unwrap();
+    boards.submit_guess("lampa").unwrap();
+
+    assert!(record_abandoned_game(
+        &mut statistics,
+        &boards.to_text(),
+        &d
+    ));
+    let record = statistics.get_record(5, 6);
+    assert_eq!(record.played, 1);
+    assert_eq!(record.wins, 0);
+
+    // finished games are already recorded, a missing one has nothing to record
+    boards
+        .submit_guess(&boards.get_games()[0].get_correct_word())
+        .unwrap();
+    assert!(!record_abandoned_game(
+        &mut statistics,
+        &boards.to_text(),
+        &d
+    ));
+    assert!(!record_abandoned_game(&mut statistics, "", &d));
+
+    let settings = Settings {
+        boards: 2,
+        ..Settings::default()
+    };
+    let boards = make_boards(&settings, &d, false, TIME, &mut Counter::default()).unwrap();
+    assert!(!record_abandoned_game(
+        &mut statistics,
+        &boards.to_text(),
+        &d
+    ));
+    assert_eq!(statistics.get_record(5, 6).played, 1);
+}
@@ -0,0 +1,117 @@
+use std::ops::RangeInclusive;
+
+#[cfg(test)]
+mod tests;
+
+const VERSION: u32 = 1;
+
+// Bounds of the numeric settings, the word length is further limited by the dictionary.
+pub const ATTEMPTS_RANGE: RangeInclusive<u32> = 1..=10;
+pub const WORD_LENGTH_RANGE: RangeInclusive<u32> = 2..=12;
+pub const BOARD_COUNTS: [u32; 4] = [1, 2, 4, 8];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Settings {
+    pub word_length: u32,
+    pub attempts: u32,
+    pub boards: u32,
+    pub hard_mode: bool,
+    pub easy_mode: bool,
+    pub absurdle: bool,
+    pub dictionary: usize,
+    pub layout: usize,
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            word_length: 5,
+            attempts: 6,
+            boards: 1,
+            hard_mode: false,
+            easy_mode: false,
+            absurdle: false,
+            dictionary: 0,
+            layout: 0,
+        }
+    }
+}
+
+impl Settings {
+    // The first line holds the format version, then one `name value` pair per line.
+    // Unknown names are skipped and missing ones keep their defaults, so fields can be added freely.
+    pub fn from_text(text: &str) -> Settings {
+        let mut result = Settings::default();
+
+        let mut lines = text.lines();
+        let version: Option<u32> = lines
+            .next()
+            .and_then(|line| line.strip_prefix("settings "))
+            .and_then(|version| version.trim().parse().ok());
+        match version {
+            Some(version) if version <= VERSION => {}
+            _ => return result,
+        }
+
+        for line in lines {
+            let (name, value) = match line.split_once(' ') {
+                Some((name, value)) => (name, value.trim()),
+                None => continue,
+            };
+
+            match name {
+                "word_length" => parse_value(value, &mut result.word_length),
+                "attempts" => parse_value(value, &mut result.attempts),
+                "boards" => parse_value(value, &mut result.boards),
+                "hard_mode" => parse_value(value, &mut result.hard_mode),
+                "easy_mode" => parse_value(value, &mut result.easy_mode),
+                "absurdle" => parse_value(value, &mut result.absurdle),
+                "dictionary" => parse_value(value, &mut result.dictionary),
+                "layout" => parse_value(value, &mut result.layout),
+                _ => {}
+            }
+        }
+
+        if !ATTEMPTS_RANGE.contains(&result.attempts) {
+            result.attempts = Settings::default().attempts;
+        }
+        if !WORD_LENGTH_RANGE.contains(&result.word_length) {
+            result.word_length = Settings::default().word_length;
+        }
+        if !BOARD_COUNTS.contains(&result.boards) {
+            result.boards = Settings::default().boards;
+        }
+
+        result
+    }
+
+    pub fn to_text(self) -> String {
+        format!(
+            "settings {}\nword_length {}\nattempts {}\nboards {}\nhard_mode {}\neasy_mode {}\nabsurdle {}\ndictionary {}\nlayout {}\n",
+            VERSION,
+            self.word_length,
+            self.attempts,
+            self.boards,
+            self.hard_mode,
+            self.easy_mode,
+            self.absurdle,
+            self.dictionary,
+            self.layout
+        )
+    }
+}
+
+// Moves `steps` places further in the allowed board counts, wrapping around.
+pub fn cycle_board_count(boards: u32, steps: usize) -> u32 {
+    let index: usize = BOARD_COUNTS
+        .iter()
+        .position(|count| *count == boards)
+        .unwrap_or(0);
+    BOARD_COUNTS[(index + steps) % BOARD_COUNTS.len()]
+}
+
+fn parse_value<T: std::str::FromStr>(text: &str, value: &mut T) {
+    if let Ok(parsed) = text.parse() {
+        *value = parsed;
+    }
+}
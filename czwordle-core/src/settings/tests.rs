@@ -0,0 +1,50 @@
+use super::Settings;
+
+#[test]
+fn settings_to_text_and_back() {
+    let settings = Settings {
+        word_length: 6,
+        attempts: 8,
+        boards: 4,
+        hard_mode: true,
+        easy_mode: false,
+        absurdle: true,
+        dictionary: 2,
+        layout: 1,
+    };
+
+    let text = settings.to_text();
+    assert!(text.starts_with("settings 1\n"));
+    assert_eq!(Settings::from_text(&text), settings);
+}
+
+#[test]
+fn settings_from_text() {
+    let text = "settings 1\nattempts 4\nhard_mode true\nword_length x\nunknown 3\n\n";
+    let settings = Settings::from_text(text);
+    assert_eq!(
+        settings,
+        Settings {
+            attempts: 4,
+            hard_mode: true,
+            ..Settings::default()
+        }
+    );
+
+    // files from newer versions and broken files are ignored
+    assert_eq!(
+        Settings::from_text("settings 2\nattempts 4\n"),
+        Settings::default()
+    );
+    assert_eq!(Settings::from_text("attempts 4\n"), Settings::default());
+    assert_eq!(Settings::from_text(""), Settings::default());
+
+    // values out of bounds are reset
+    assert_eq!(
+        Settings::from_text("settings 1\nattempts 0\nword_length 40\nboards 3\nlayout 3\n"),
+        Settings {
+            layout: 3,
+            ..Settings::default()
+        }
+    );
+}
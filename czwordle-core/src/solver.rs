@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+use crate::boards::Boards;
+use crate::candidates::Candidates;
+use crate::game::Game;
+use crate::game::GameState;
+use crate::game::Score;
+
+#[cfg(test)]
+mod tests;
+
+// Ranking every candidate as a guess takes too long with large dictionaries, only this many spread over them are tried.
+const MAXIMUM_RANKED_GUESSES: usize = 200;
+
+// The score codes of longer words would not fit into 64 bits.
+const MAXIMUM_SCORED_LETTERS: usize = 40;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Suggestion {
+    pub word: String,
+    // expected information of the guess in bits, the more the better the remaining candidates are split
+    pub entropy: f64,
+}
+
+// Works out the answers a game can still have from its guesses and suggests the next guess.
+pub struct Solver<'g, 'd> {
+    game: &'g Game<'d>,
+    candidates: Candidates,
+    // the letters of the candidates as they are compared, folded once for all ranked guesses
+    candidate_letters: Vec<Vec<char>>,
+}
+
+impl<'g, 'd> Solver<'g, 'd> {
+    pub fn new(game: &'g Game<'d>) -> Solver<'g, 'd> {
+        let mut candidates = Candidates::new(&game.get_word_list());
+
+        for guess in game.get_guesses() {
+            let guess_score = Score {
+                green_positions: guess.green_positions.clone(),
+                yellow_positions: guess.yellow_positions.clone(),
+            };
+            candidates.keep_matching(|word| game.score(&guess.word, word), &guess_score);
+        }
+
+        let candidate_letters: Vec<Vec<char>> = candidates
+            .get_words()
+            .iter()
+            .map(|word| game.fold_letters(word))
+            .collect();
+
+        Solver {
+            game,
+            candidates,
+            candidate_letters,
+        }
+    }
+
+    pub fn get_candidates(&self) -> &Vec<String> {
+        self.candidates.get_words()
+    }
+
+    // The best guesses among the candidates, sorted from the most informative.
+    // Only candidates are suggested, so every suggestion can win and is allowed in the hard mode.
+    pub fn suggest(&self, count: usize) -> Vec<Suggestion> {
+        let candidates: &Vec<String> = self.candidates.get_words();
+        let step: usize = candidates.len().div_ceil(MAXIMUM_RANKED_GUESSES).max(1);
+
+        let mut suggestions: Vec<Suggestion> = candidates
+            .iter()
+            .step_by(step)
+            .map(|guess| Suggestion {
+                word: guess.clone(),
+                entropy: self.get_entropy(guess),
+            })
+            .collect();
+
+        // the sort is stable, equal guesses keep the order of the word list
+        suggestions.sort_by(|a, b| b.entropy.total_cmp(&a.entropy));
+        suggestions.truncate(count);
+        suggestions
+    }
+
+    fn get_entropy(&self, guess: &str) -> f64 {
+        let guess_letters: Vec<char> = self.game.fold_letters(guess);
+
+        let mut group_sizes: HashMap<u64, usize> = HashMap::new();
+        for letters in &self.candidate_letters {
+            *group_sizes
+                .entry(get_score_code(&guess_letters, letters))
+                .or_insert(0) += 1;
+        }
+
+        let total = self.candidate_letters.len() as f64;
+        group_sizes
+            .values()
+            .map(|size| {
+                let probability = *size as f64 / total;
+                -probability * probability.log2()
+            })
+            .sum()
+    }
+}
+
+// The score of the guess against the answer packed into a number, a digit per letter: 2 green, 1 yellow, 0 gray.
+// Scores like `game::score` without allocating, the ranking runs it for every guess and every candidate.
+fn get_score_code(guess: &[char], answer: &[char]) -> u64 {
+    debug_assert!(guess.len() == answer.len() && guess.len() <= MAXIMUM_SCORED_LETTERS);
+
+    let mut greens: u64 = 0;
+    for (i, (guess_letter, answer_letter)) in guess.iter().zip(answer).enumerate() {
+        if guess_letter == answer_letter {
+            greens |= 1 << i;
+        }
+    }
+
+    // the letters of the answer taken by a green or a yellow, each can make only one
+    let mut matched: u64 = greens;
+    let mut code: u64 = 0;
+    for (i, guess_letter) in guess.iter().enumerate() {
+        let digit: u64 = if greens & (1 << i) != 0 {
+            2
+        } else if let Some(j) =
+            (0..answer.len()).find(|j| matched & (1 << j) == 0 && answer[*j] == *guess_letter)
+        {
+            matched |= 1 << j;
+            1
+        } else {
+            0
+        };
+        code = code * 3 + digit;
+    }
+
+    code
+}
+
+// The candidate count and the best guesses for the first unsolved board.
+pub fn get_hint_message(boards: &Boards, suggestion_count: usize) -> String {
+    get_game_hint_message(get_hinted_game(boards), suggestion_count)
+}
+
+// Hints already worked out, ranking the guesses is slow with large dictionaries.
+// The candidates depend only on the word list, the easy mode and the scored guesses, so the opening hint
+// is ranked once per word list and pressing HINT again ranks nothing. Clear it when the dictionary changes.
+#[derive(Default)]
+pub struct Hints {
+    messages: HashMap<String, String>,
+}
+
+impl Hints {
+    pub fn new() -> Hints {
+        Hints::default()
+    }
+
+    pub fn get_message(&mut self, boards: &Boards, suggestion_count: usize) -> String {
+        let game: &Game = get_hinted_game(boards);
+        let guesses: Vec<String> = game
+            .get_guesses()
+            .iter()
+            .map(|guess| {
+                format!(
+                    "{}{:?}{:?}",
+                    guess.word, guess.green_positions, guess.yellow_positions
+                )
+            })
+            .collect();
+        let key = format!(
+            "{} {} {} {}",
+            game.get_word_length(),
+            game.is_easy_mode(),
+            suggestion_count,
+            guesses.join(" ")
+        );
+
+        self.messages
+            .entry(key)
+            .or_insert_with(|| get_game_hint_message(game, suggestion_count))
+            .clone()
+    }
+
+    pub fn clear(&mut self) {
+        self.messages.clear();
+    }
+}
+
+fn get_hinted_game<'b, 'd>(boards: &'b Boards<'d>) -> &'b Game<'d> {
+    boards
+        .get_games()
+        .iter()
+        .find(|game| matches!(game.get_game_state(), GameState::Ongoing(_)))
+        .expect("hints are shown only during the game")
+}
+
+fn get_game_hint_message(game: &Game, suggestion_count: usize) -> String {
+    let solver = Solver::new(game);
+    let words: Vec<String> = solver
+        .suggest(suggestion_count)
+        .into_iter()
+        .map(|suggestion| suggestion.word)
+        .collect();
+
+    format!(
+        "Zbývá {}: {}",
+        solver.get_candidates().len(),
+        words.join(" ")
+    )
+}
@@ -0,0 +1,132 @@
+use crate::boards::Boards;
+use crate::dictionary::Dictionary;
+use crate::game::score;
+use crate::game::Game;
+use crate::game::GameState;
+
+use super::get_hint_message;
+use super::get_score_code;
+use super::Hints;
+use super::Solver;
+
+fn make_game<'d>(d: &'d Dictionary, word: &str, maximum_tries: u32) -> Game<'d> {
+    let word_list = d.get_word_list(word.chars().count() as u32).unwrap();
+    Game::new_with_answer(maximum_tries, word, word_list)
+}
+
+// Plays the best suggestion until the game is over.
+fn play(game: &mut Game) -> GameState {
+    while let GameState::Ongoing(_) = game.get_game_state() {
+        let suggestion = Solver::new(game).suggest(1).remove(0);
+        game.submit_guess(&suggestion.word).unwrap();
+    }
+    game.get_game_state()
+}
+
+#[test]
+fn solver_candidates() {
+    let d = Dictionary::new("pivo\nauto\nkolo\nsova\nlano", "");
+    let mut game = make_game(&d, "kolo", 6);
+
+    assert_eq!(Solver::new(&game).get_candidates().len(), 5);
+
+    // only the words ending with O and sharing no other letter with PIVO are left
+    game.submit_guess("pivo").unwrap();
+    assert_eq!(
+        Solver::new(&game).get_candidates(),
+        &vec!["AUTO", "KOLO", "LANO"]
+    );
+}
+
+#[test]
+fn solver_suggest() {
+    let d = Dictionary::new("pivo\nauto\nkolo\nsova\nlano", "");
+    let mut game = make_game(&d, "kolo", 6);
+    game.submit_guess("pivo").unwrap();
+
+    // every candidate tells the three apart, the first ones are suggested
+    let suggestions = Solver::new(&game).suggest(2);
+    assert_eq!(suggestions.len(), 2);
+    assert_eq!(suggestions[0].word, "AUTO");
+    assert_eq!(suggestions[1].word, "KOLO");
+    assert!((suggestions[0].entropy - 3.0_f64.log2()).abs() < 1e-9);
+
+    let suggestions = Solver::new(&game).suggest(10);
+    assert_eq!(suggestions.len(), 3);
+}
+
+#[test]
+fn dictionary_is_solvable() {
+    let d = Dictionary::new(
+        include_str!("../../../data/dictionary.txt"),
+        include_str!("../../../data/guesses.txt"),
+    );
+
+    for word_length in d.get_word_lengths() {
+        for word in d.get_word_list(word_length).unwrap().get_answers() {
+            let mut game = make_game(&d, word, 6);
+            assert!(
+                matches!(play(&mut game), GameState::Win(_)),
+                "{} is not solved in 6 tries",
+                word
+            );
+        }
+    }
+}
+
+#[test]
+fn score_code_matches_score() {
+    let words = [
+        "PACKA", "PAPPA", "AAAAA", "AKPXX", "KOLOS", "OOKOO", "PAPÍR", "RPAPÍ", "PPPPP", "OPPOP",
+    ];
+
+    for guess in words {
+        for answer in words {
+            let result = score(guess, answer);
+            let expected: u64 = (0_u32..5).fold(0, |code, i| {
+                let digit = if result.green_positions.contains(&i) {
+                    2
+                } else if result.yellow_positions.contains(&i) {
+                    1
+                } else {
+                    0
+                };
+                code * 3 + digit
+            });
+
+            let guess_letters: Vec<char> = guess.chars().collect();
+            let answer_letters: Vec<char> = answer.chars().collect();
+            assert_eq!(
+                get_score_code(&guess_letters, &answer_letters),
+                expected,
+                "{} against {}",
+                guess,
+                answer
+            );
+        }
+    }
+}
+
+#[test]
+fn hints_are_cached() {
+    let d = Dictionary::new("pivo\nauto\nkolo\nsova\nlano", "");
+    let mut boards = Boards::new(vec![make_game(&d, "kolo", 6)]);
+    let mut hints = Hints::new();
+
+    let message = hints.get_message(&boards, 3);
+    assert_eq!(message, get_hint_message(&boards, 3));
+    assert_eq!(hints.get_message(&boards, 3), message);
+    assert_eq!(hints.messages.len(), 1);
+
+    // another answer with the same opening shares its hint
+    let other = Boards::new(vec![make_game(&d, "auto", 6)]);
+    assert_eq!(hints.get_message(&other, 3), message);
+    assert_eq!(hints.messages.len(), 1);
+
+    boards.submit_guess("pivo").unwrap();
+    assert_eq!(hints.get_message(&boards, 3), "Zbývá 3: AUTO KOLO LANO");
+    assert_eq!(hints.messages.len(), 2);
+
+    hints.clear();
+    assert!(hints.messages.is_empty());
+}
@@ -0,0 +1,142 @@
+use std::collections::BTreeMap;
+
+use crate::game::GameState;
+use crate::settings::ATTEMPTS_RANGE;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub played: u32,
+    pub wins: u32,
+    pub current_streak: u32,
+    pub max_streak: u32,
+    // number of wins for each guess count, index 0 is a win in the first guess
+    pub guess_distribution: Vec<u32>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Statistics {
+    records: BTreeMap<(u32, u32), Record>,
+}
+
+impl Record {
+    pub fn new(attempts: u32) -> Record {
+        Record {
+            played: 0,
+            wins: 0,
+            current_streak: 0,
+            max_streak: 0,
+            guess_distribution: vec![0; attempts as usize],
+        }
+    }
+
+    pub fn get_win_percentage(&self) -> u32 {
+        if self.played == 0 {
+            return 0;
+        }
+
+        (self.wins as f32 * 100.0 / self.played as f32).round() as u32
+    }
+}
+
+impl Default for Statistics {
+    fn default() -> Statistics {
+        Statistics::new()
+    }
+}
+
+impl Statistics {
+    pub fn new() -> Statistics {
+        Statistics {
+            records: BTreeMap::new(),
+        }
+    }
+
+    pub fn from_text(text: &str) -> Statistics {
+        let mut result = Statistics::new();
+
+        for line in text.lines() {
+            let numbers: Vec<u32> = match line.split_whitespace().map(|n| n.parse()).collect() {
+                Ok(numbers) => numbers,
+                Err(_) => continue,
+            };
+
+            // the attempts are checked first, a huge count from a broken file must not overflow
+            if numbers.len() < 6
+                || !ATTEMPTS_RANGE.contains(&numbers[1])
+                || numbers.len() != 6 + numbers[1] as usize
+            {
+                continue;
+            }
+
+            result.records.insert(
+                (numbers[0], numbers[1]),
+                Record {
+                    played: numbers[2],
+                    wins: numbers[3],
+                    current_streak: numbers[4],
+                    max_streak: numbers[5],
+                    guess_distribution: numbers[6..].to_vec(),
+                },
+            );
+        }
+
+        result
+    }
+
+    // one line per record: word length, attempts, played, wins, streaks and the distribution
+    pub fn to_text(&self) -> String {
+        let mut result = String::new();
+
+        for ((word_length, attempts), record) in &self.records {
+            result.push_str(&format!(
+                "{} {} {} {} {} {}",
+                word_length,
+                attempts,
+                record.played,
+                record.wins,
+                record.current_streak,
+                record.max_streak
+            ));
+            for count in &record.guess_distribution {
+                result.push_str(&format!(" {}", count));
+            }
+            result.push('\n');
+        }
+
+        result
+    }
+
+    pub fn add_game(&mut self, word_length: u32, attempts: u32, game_state: &GameState) {
+        let record = self
+            .records
+            .entry((word_length, attempts))
+            .or_insert_with(|| Record::new(attempts));
+
+        match game_state {
+            GameState::Win(guess_count) => {
+                record.played += 1;
+                record.wins += 1;
+                record.current_streak += 1;
+                record.max_streak = std::cmp::max(record.max_streak, record.current_streak);
+                if *guess_count > 0 && *guess_count <= attempts {
+                    record.guess_distribution[*guess_count as usize - 1] += 1;
+                }
+            }
+            GameState::Lose => {
+                record.played += 1;
+                record.current_streak = 0;
+            }
+            GameState::Ongoing(_) => {}
+        }
+    }
+
+    pub fn get_record(&self, word_length: u32, attempts: u32) -> Record {
+        self.records
+            .get(&(word_length, attempts))
+            .cloned()
+            .unwrap_or_else(|| Record::new(attempts))
+    }
+}
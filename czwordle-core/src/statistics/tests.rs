@@ -0,0 +1,72 @@
+use crate::game::GameState;
+
+use super::Record;
+use super::Statistics;
+
+#[test]
+fn add_game() {
+    let mut s = Statistics::new();
+
+    s.add_game(5, 6, &GameState::Win(3));
+    s.add_game(5, 6, &GameState::Win(1));
+    s.add_game(5, 6, &GameState::Lose);
+    s.add_game(5, 6, &GameState::Win(3));
+    s.add_game(5, 6, &GameState::Ongoing(2));
+
+    let r = s.get_record(5, 6);
+    assert_eq!(r.played, 4);
+    assert_eq!(r.wins, 3);
+    assert_eq!(r.current_streak, 1);
+    assert_eq!(r.max_streak, 2);
+    assert_eq!(r.guess_distribution, vec![1, 0, 2, 0, 0, 0]);
+    assert_eq!(r.get_win_percentage(), 75);
+}
+
+#[test]
+fn records_are_separate_for_settings() {
+    let mut s = Statistics::new();
+
+    s.add_game(5, 6, &GameState::Win(2));
+    s.add_game(6, 6, &GameState::Lose);
+    s.add_game(5, 3, &GameState::Win(3));
+
+    assert_eq!(
+        s.get_record(5, 6).guess_distribution,
+        vec![0, 1, 0, 0, 0, 0]
+    );
+    assert_eq!(s.get_record(6, 6).wins, 0);
+    assert_eq!(s.get_record(6, 6).played, 1);
+    assert_eq!(s.get_record(5, 3).guess_distribution, vec![0, 0, 1]);
+    assert_eq!(s.get_record(7, 4), Record::new(4));
+}
+
+#[test]
+fn text_round_trip() {
+    let mut s = Statistics::new();
+    s.add_game(5, 6, &GameState::Win(4));
+    s.add_game(5, 6, &GameState::Lose);
+    s.add_game(4, 2, &GameState::Win(1));
+
+    let text = s.to_text();
+    assert_eq!(text, "4 2 1 1 1 1 1 0\n5 6 2 1 0 1 0 0 0 1 0 0\n");
+    assert_eq!(Statistics::from_text(&text), s);
+}
+
+#[test]
+fn from_text_skips_broken_lines() {
+    let s = Statistics::from_text("5 6 1 1 1 1 1\nnonsense\n4 2 1 1 1 1 1 0\n");
+
+    assert_eq!(s.get_record(5, 6), Record::new(6));
+    assert_eq!(s.get_record(4, 2).wins, 1);
+}
+
+#[test]
+fn from_text_skips_attempts_out_of_range() {
+    let s = Statistics::from_text(
+        "5 4294967295 1 1 1 1 1\n5 0 1 1 1 1\n5 11 1 1 1 1 0 0 0 0 0 0 0 0 0 0 1\n",
+    );
+
+    assert_eq!(s.get_record(5, 11), Record::new(11));
+    assert_eq!(s.get_record(5, 0), Record::new(0));
+    assert_eq!(s.to_text(), "");
+}
@@ -0,0 +1,32 @@
+// Key-value storage in files, one `czwordle/<key>.txt` per key under the given directory.
+// The native frontends pass the user's config directory, so they share the settings,
+// statistics and a saved game.
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+#[cfg(test)]
+mod tests;
+
+pub fn load(directory: &Path, key: &str) -> Option<String> {
+    fs::read_to_string(file_path(directory, key)).ok()
+}
+
+pub fn save(directory: &Path, key: &str, value: &str) {
+    let path: PathBuf = file_path(directory, key);
+
+    if let Some(directory) = path.parent() {
+        if fs::create_dir_all(directory).is_err() {
+            return;
+        }
+    }
+
+    if fs::write(&path, value).is_err() {
+        println!("ERROR: could not save {}", path.display());
+    }
+}
+
+fn file_path(directory: &Path, key: &str) -> PathBuf {
+    directory.join("czwordle").join(format!("{}.txt", key))
+}
@@ -0,0 +1,19 @@
+use std::fs;
+use std::path::PathBuf;
+
+use super::load;
+use super::save;
+
+#[test]
+fn saved_values_are_loaded() {
+    let directory: PathBuf = std::env::temp_dir().join(format!("czwordle-{}", std::process::id()));
+
+    assert_eq!(load(&directory, "game"), None);
+    save(&directory, "game", "kočka");
+    save(&directory, "daily", "1752");
+    assert_eq!(load(&directory, "game").as_deref(), Some("kočka"));
+    assert_eq!(load(&directory, "daily").as_deref(), Some("1752"));
+    assert!(directory.join("czwordle").join("game.txt").is_file());
+
+    fs::remove_dir_all(&directory).unwrap();
+}
@@ -0,0 +1,514 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::io::BufRead;
+use std::io::Write;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use czwordle_core::alphabet::Alphabet;
+use czwordle_core::boards::Boards;
+use czwordle_core::catalog;
+use czwordle_core::catalog::DictionaryFile;
+use czwordle_core::daily;
+use czwordle_core::dictionary::Dictionary;
+use czwordle_core::game::get_error_message;
+use czwordle_core::game::GameState;
+use czwordle_core::layout;
+use czwordle_core::layout::KeyboardLayout;
+use czwordle_core::random::SeededRandom;
+use czwordle_core::session;
+use czwordle_core::session::DictionaryTexts;
+use czwordle_core::settings;
+use czwordle_core::settings::Settings;
+use czwordle_core::solver::Hints;
+use czwordle_core::statistics::Statistics;
+
+use crate::screen;
+use crate::storage;
+
+// How many best guesses the hint shows.
+const HINT_SUGGESTIONS: usize = 3;
+
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum ApplicationState {
+    Menu,
+    NewGame,
+    Daily,
+    Continue,
+    Quit,
+}
+
+// The items of the main menu, the same as in the window version.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+enum MenuItem {
+    Continue,
+    NewGame,
+    Daily,
+    Attempts,
+    WordLength,
+    Boards,
+    HardMode,
+    EasyMode,
+    Absurdle,
+    Dictionary,
+    Keyboard,
+    Statistics,
+    Quit,
+}
+
+pub struct App<'s> {
+    pub settings: Settings,
+
+    builtin_answers_file: &'s str,
+    builtin_guesses_file: &'s str,
+    dictionaries: Vec<DictionaryFile>,
+    layouts: Vec<KeyboardLayout>,
+    last_daily_puzzle: Option<u32>,
+    statistics: Statistics,
+    random: SeededRandom,
+    hints: Hints,
+}
+
+impl<'s, 'd> App<'s> {
+    pub fn new(builtin_answers_file: &'s str, builtin_guesses_file: &'s str, seed: u64) -> App<'s> {
+        App {
+            builtin_answers_file,
+            builtin_guesses_file,
+            dictionaries: Vec::new(),
+            layouts: vec![KeyboardLayout::default()],
+            last_daily_puzzle: storage::load("daily").and_then(|text| text.trim().parse().ok()),
+            statistics: Statistics::from_text(&storage::load("statistics").unwrap_or_default()),
+            settings: Settings::from_text(&storage::load("settings").unwrap_or_default()),
+            random: SeededRandom::new(seed),
+            hints: Hints::new(),
+        }
+    }
+
+    pub fn load_catalog(&mut self) {
+        match fs::read_to_string(catalog::CATALOG_PATH) {
+            Ok(text_file) => self.dictionaries = catalog::parse_catalog(&text_file),
+            Err(_) => println!("ERROR: could not load {}", catalog::CATALOG_PATH),
+        }
+    }
+
+    pub fn load_layouts(&mut self) {
+        match fs::read_to_string(layout::LAYOUTS_PATH) {
+            Ok(text_file) => {
+                let layouts = layout::parse_layouts(&text_file);
+                if !layouts.is_empty() {
+                    self.layouts = layouts;
+                }
+            }
+            Err(_) => println!("ERROR: could not load {}", layout::LAYOUTS_PATH),
+        }
+    }
+
+    // Loads the dictionary chosen in the settings, the built-in words are used when it cannot be loaded.
+    pub fn load_dictionary(&mut self) -> Dictionary {
+        let file: DictionaryFile = match self.dictionaries.get(self.settings.dictionary) {
+            Some(file) => file.clone(),
+            None => return self.make_builtin_dictionary(),
+        };
+
+        let read_optional = |path: &Option<String>| match path {
+            Some(path) => fs::read_to_string(path),
+            None => Ok(String::new()),
+        };
+        let answers = fs::read_to_string(&file.answers_path);
+        let guesses = read_optional(&file.guesses_path);
+        let affixes = read_optional(&file.affix_path);
+        let alphabet = match &file.alphabet_path {
+            Some(path) => fs::read_to_string(path).map(Some),
+            None => Ok(None),
+        };
+
+        match (answers, guesses, affixes, alphabet) {
+            (Ok(answers), Ok(guesses), Ok(affixes), Ok(alphabet)) => {
+                session::make_dictionary(&DictionaryTexts {
+                    answers,
+                    guesses,
+                    filter: file.filter,
+                    affixes,
+                    alphabet,
+                })
+            }
+            _ => {
+                println!("ERROR: could not load dictionary {}", file.name);
+                self.make_builtin_dictionary()
+            }
+        }
+    }
+
+    pub fn run_menu_loop(&mut self, dictionary: &mut Dictionary) -> ApplicationState {
+        let initial_settings: Settings = self.settings;
+        let mut message = String::new();
+
+        let state: ApplicationState = loop {
+            let puzzle_number = daily::puzzle_number(get_time());
+            let daily_played: bool = self.last_daily_puzzle == Some(puzzle_number);
+            let word_counts: BTreeMap<u32, usize> = session::get_word_counts(dictionary);
+            let items: Vec<(MenuItem, String)> = self.get_menu_items(
+                puzzle_number,
+                daily_played,
+                App::load_saved_game(dictionary).is_some(),
+                &word_counts,
+            );
+
+            print!(
+                "{}{}čwordle{}  seed {}\n\n",
+                screen::CLEAR,
+                screen::TITLE,
+                screen::RESET,
+                self.random.get_seed()
+            );
+            for (number, (_, label)) in (1_usize..).zip(&items) {
+                println!("{:>3}  {}", number, label);
+            }
+            println!("\n{}", message);
+            println!("Choose an item by its number, ATTEMPTS and WORD LENGTH may be followed by a new value.");
+            message.clear();
+
+            let line: String = match read_line() {
+                Some(line) => line,
+                None => break ApplicationState::Quit,
+            };
+            let mut parts = line.split_whitespace();
+            let item: Option<MenuItem> = parts
+                .next()
+                .and_then(|number| number.parse::<usize>().ok())
+                .and_then(|number| number.checked_sub(1))
+                .and_then(|index| items.get(index))
+                .map(|(item, _)| *item);
+            let value: Option<u32> = parts.next().and_then(|value| value.parse().ok());
+
+            let dictionary_count: usize = self.dictionaries.len();
+            let layout_count: usize = self.layouts.len() + 1;
+            match item {
+                Some(MenuItem::Continue) => break ApplicationState::Continue,
+                Some(MenuItem::NewGame) if word_counts.contains_key(&self.settings.word_length) => {
+                    break ApplicationState::NewGame
+                }
+                Some(MenuItem::Daily)
+                    if !daily_played && word_counts.contains_key(&daily::WORD_LENGTH) =>
+                {
+                    break ApplicationState::Daily
+                }
+                Some(MenuItem::NewGame) | Some(MenuItem::Daily) => {
+                    message = "This game is not available.".to_string()
+                }
+                Some(MenuItem::Attempts) => {
+                    self.settings.attempts = match value {
+                        Some(attempts) if settings::ATTEMPTS_RANGE.contains(&attempts) => attempts,
+                        Some(_) => {
+                            message = format!(
+                                "ATTEMPTS must be from {} to {}.",
+                                settings::ATTEMPTS_RANGE.start(),
+                                settings::ATTEMPTS_RANGE.end()
+                            );
+                            self.settings.attempts
+                        }
+                        None if self.settings.attempts < *settings::ATTEMPTS_RANGE.end() => {
+                            self.settings.attempts + 1
+                        }
+                        None => *settings::ATTEMPTS_RANGE.start(),
+                    }
+                }
+                Some(MenuItem::WordLength) => {
+                    let next_length: Option<u32> = match value {
+                        Some(word_length) => {
+                            word_counts.get_key_value(&word_length).map(|(l, _)| *l)
+                        }
+                        None => word_counts
+                            .range(self.settings.word_length + 1..)
+                            .chain(word_counts.iter())
+                            .next()
+                            .map(|(word_length, _)| *word_length),
+                    };
+                    match next_length {
+                        Some(word_length) => self.settings.word_length = word_length,
+                        None => message = "The dictionary has no words of this length.".to_string(),
+                    }
+                }
+                Some(MenuItem::Boards) => {
+                    self.settings.boards = settings::cycle_board_count(self.settings.boards, 1)
+                }
+                Some(MenuItem::HardMode) => self.settings.hard_mode = !self.settings.hard_mode,
+                Some(MenuItem::EasyMode) => self.settings.easy_mode = !self.settings.easy_mode,
+                Some(MenuItem::Absurdle) => self.settings.absurdle = !self.settings.absurdle,
+                Some(MenuItem::Dictionary) if dictionary_count > 0 => {
+                    self.settings.dictionary = (self.settings.dictionary + 1) % dictionary_count;
+                    *dictionary = self.load_dictionary();
+                    self.hints.clear();
+                }
+                Some(MenuItem::Keyboard) => {
+                    self.settings.layout = (self.settings.layout + 1) % layout_count
+                }
+                Some(MenuItem::Statistics) => self.run_statistics_loop(),
+                Some(MenuItem::Quit) => break ApplicationState::Quit,
+                _ => {}
+            }
+        };
+
+        if self.settings != initial_settings {
+            storage::save("settings", &self.settings.to_text());
+        }
+
+        state
+    }
+
+    pub fn run_game_loop(
+        &mut self,
+        dictionary: &'d Dictionary,
+        application_state: ApplicationState,
+    ) -> ApplicationState {
+        let boards = match application_state {
+            ApplicationState::Continue => {
+                App::load_saved_game(dictionary).or_else(|| self.make_boards(dictionary, false))
+            }
+            _ => self.make_boards(dictionary, application_state == ApplicationState::Daily),
+        };
+        let mut boards: Boards = match boards {
+            Some(boards) => boards,
+            None => return ApplicationState::Menu,
+        };
+        let saved_game: String = storage::load("game").unwrap_or_default();
+        if application_state != ApplicationState::Continue
+            && session::record_abandoned_game(&mut self.statistics, &saved_game, dictionary)
+        {
+            storage::save("statistics", &self.statistics.to_text());
+        }
+        if let Some(puzzle_number) = boards.get_puzzle_number() {
+            self.last_daily_puzzle = Some(puzzle_number);
+            storage::save("daily", &puzzle_number.to_string());
+        }
+        storage::save("game", &boards.to_text());
+
+        let mut message = String::new();
+        while let GameState::Ongoing(_) = boards.get_game_state() {
+            self.draw_game(&boards, &message);
+            println!("Type a word and press Enter, `?` shows a hint, `!` goes back to the menu.");
+            message.clear();
+
+            let line: String = match read_line() {
+                Some(line) => line,
+                None => return ApplicationState::Quit,
+            };
+            match line.trim() {
+                "" => {}
+                "?" => message = self.hints.get_message(&boards, HINT_SUGGESTIONS),
+                "!" => return ApplicationState::Menu,
+                word => match to_guess(word, boards.get_alphabet()) {
+                    Err(letter) => message = format!("Neznámé písmeno {}", letter),
+                    Ok(guess) => match boards.submit_guess(&guess) {
+                        Ok(_) => storage::save("game", &boards.to_text()),
+                        Err(error) => message = get_error_message(&error, boards.get_word_length()),
+                    },
+                },
+            }
+        }
+
+        if session::record_game(&mut self.statistics, &boards) {
+            storage::save("statistics", &self.statistics.to_text());
+        }
+        storage::save("game", "");
+
+        self.run_game_over_loop(&boards)
+    }
+
+    fn run_game_over_loop(&self, boards: &Boards) -> ApplicationState {
+        let mut message = String::new();
+
+        loop {
+            self.draw_game(boards, &message);
+            println!("  1  NEW GAME\n  2  MENU\n  3  SHARE");
+            message.clear();
+
+            match read_line().as_deref().map(str::trim) {
+                None => return ApplicationState::Quit,
+                Some("1") => return ApplicationState::NewGame,
+                Some("2") => return ApplicationState::Menu,
+                Some("3") => message = boards.make_share_grid(),
+                Some(_) => {}
+            }
+        }
+    }
+
+    fn run_statistics_loop(&self) {
+        let record = self
+            .statistics
+            .get_record(self.settings.word_length, self.settings.attempts);
+
+        print!(
+            "{}{}",
+            screen::CLEAR,
+            screen::draw_statistics(self.settings.word_length, self.settings.attempts, &record)
+        );
+        println!("\nPress Enter to go back to the menu.");
+        read_line();
+    }
+
+    fn draw_game(&self, boards: &Boards, message: &str) {
+        // the last layout choice is the alphabet itself
+        let alphabet: &Alphabet = boards.get_alphabet();
+        let layout: KeyboardLayout = match self.layouts.get(self.settings.layout) {
+            Some(layout) => layout.clone(),
+            None => KeyboardLayout::from_alphabet(alphabet),
+        };
+
+        print!("{}", screen::CLEAR);
+        if let Some(puzzle_number) = boards.get_puzzle_number() {
+            println!("#{}\n", puzzle_number);
+        }
+        println!("{}", screen::draw_boards(boards));
+        println!("{}\n", screen::draw_keyboard(&layout.fit(alphabet), boards));
+        if !message.is_empty() {
+            println!("{}\n", message);
+        }
+    }
+
+    fn get_menu_items(
+        &self,
+        puzzle_number: u32,
+        daily_played: bool,
+        has_saved_game: bool,
+        word_counts: &BTreeMap<u32, usize>,
+    ) -> Vec<(MenuItem, String)> {
+        let settings: &Settings = &self.settings;
+        let word_count: usize = *word_counts.get(&settings.word_length).unwrap_or(&0);
+        let on_off = |value: bool| if value { "ON" } else { "OFF" };
+        let one_board_only = |value: bool| {
+            if value && settings.boards > 1 {
+                " (ONE BOARD ONLY)"
+            } else {
+                ""
+            }
+        };
+
+        let mut result: Vec<(MenuItem, String)> = Vec::new();
+        if has_saved_game {
+            result.push((MenuItem::Continue, "CONTINUE".to_string()));
+        }
+        result.push((
+            MenuItem::NewGame,
+            if word_count > 0 {
+                "NEW GAME".to_string()
+            } else {
+                "NEW GAME (UNAVAILABLE)".to_string()
+            },
+        ));
+        result.push((
+            MenuItem::Daily,
+            if !word_counts.contains_key(&daily::WORD_LENGTH) {
+                "DAILY (UNAVAILABLE)".to_string()
+            } else if daily_played {
+                format!("DAILY #{} (DONE)", puzzle_number)
+            } else {
+                format!("DAILY #{}", puzzle_number)
+            },
+        ));
+        result.extend([
+            (
+                MenuItem::Attempts,
+                format!("{} ATTEMPTS", settings.attempts),
+            ),
+            (
+                MenuItem::WordLength,
+                format!(
+                    "{} WORD LENGTH ({} WORDS)",
+                    settings.word_length, word_count
+                ),
+            ),
+            (
+                MenuItem::Boards,
+                format!(
+                    "BOARDS {} ({} ATTEMPTS)",
+                    settings.boards,
+                    settings.attempts + settings.boards - 1
+                ),
+            ),
+            (
+                MenuItem::HardMode,
+                format!("HARD MODE {}", on_off(settings.hard_mode)),
+            ),
+            (
+                MenuItem::EasyMode,
+                format!("EASY MODE {}", on_off(settings.easy_mode)),
+            ),
+            (
+                MenuItem::Absurdle,
+                format!(
+                    "ABSURDLE {}{}",
+                    on_off(settings.absurdle),
+                    one_board_only(settings.absurdle)
+                ),
+            ),
+            (
+                MenuItem::Dictionary,
+                format!(
+                    "DICTIONARY {}",
+                    self.dictionaries
+                        .get(settings.dictionary)
+                        .map_or("BUILT-IN", |file| file.name.as_str())
+                ),
+            ),
+            (
+                MenuItem::Keyboard,
+                format!(
+                    "KEYBOARD {}",
+                    self.layouts
+                        .get(settings.layout)
+                        .map_or("ABC", |layout| layout.name.as_str())
+                ),
+            ),
+            (MenuItem::Statistics, "STATISTICS".to_string()),
+            (MenuItem::Quit, "QUIT".to_string()),
+        ]);
+
+        result
+    }
+
+    fn make_builtin_dictionary(&self) -> Dictionary {
+        Dictionary::new(self.builtin_answers_file, self.builtin_guesses_file)
+    }
+
+    fn load_saved_game(dictionary: &'d Dictionary) -> Option<Boards<'d>> {
+        session::load_saved_game(&storage::load("game")?, dictionary)
+    }
+
+    fn make_boards(&mut self, dictionary: &'d Dictionary, daily: bool) -> Option<Boards<'d>> {
+        session::make_boards(
+            &self.settings,
+            dictionary,
+            daily,
+            get_time(),
+            &mut self.random,
+        )
+    }
+}
+
+// The typed word in uppercase, or the first character outside of the alphabet.
+fn to_guess(word: &str, alphabet: &Alphabet) -> Result<String, char> {
+    word.chars()
+        .map(|c| alphabet.to_uppercase(c).ok_or(c))
+        .collect()
+}
+
+// A line typed by the player, `None` when the input is closed.
+fn read_line() -> Option<String> {
+    print!("> ");
+    io::stdout().flush().ok()?;
+
+    let mut line = String::new();
+    match io::stdin().lock().read_line(&mut line) {
+        Ok(0) | Err(_) => None,
+        Ok(_) => Some(line),
+    }
+}
+
+// Seconds since the Unix epoch.
+fn get_time() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0.0, |duration| duration.as_secs_f64())
+}
@@ -0,0 +1,62 @@
+// čwordle in a terminal, for playing without a window, e.g. over SSH.
+// The dictionaries and keyboard layouts are loaded from `data/` like in the window version.
+
+mod app;
+use app::App;
+use app::ApplicationState;
+
+mod screen;
+
+mod storage;
+
+use std::env;
+use std::process;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use czwordle_core::random;
+
+const USAGE: &str = "usage: czwordle-tui [--seed N]";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let seed: u64 = match random::parse_seed(&args) {
+        Ok(seed) => seed.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |duration| duration.as_millis() as u64)
+        }),
+        Err(message) => {
+            println!("ERROR: {}", message);
+            println!("{}", USAGE);
+            process::exit(1);
+        }
+    };
+
+    let mut app = App::new(
+        std::include_str!("../../data/dictionary.txt"),
+        std::include_str!("../../data/guesses.txt"),
+        seed,
+    );
+
+    app.load_catalog();
+    app.load_layouts();
+
+    let mut dictionary = app.load_dictionary();
+
+    let mut application_state: ApplicationState = ApplicationState::Menu;
+
+    loop {
+        if application_state != ApplicationState::NewGame {
+            application_state = app.run_menu_loop(&mut dictionary);
+            if application_state == ApplicationState::Quit {
+                return;
+            }
+        }
+
+        application_state = app.run_game_loop(&dictionary, application_state);
+        if application_state == ApplicationState::Quit {
+            return;
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::HashSet;
+
+use czwordle_core::boards::Boards;
+use czwordle_core::game::Game;
+use czwordle_core::game::GameState;
+use czwordle_core::game::Guess;
+use czwordle_core::layout::KeyKind;
+use czwordle_core::letters::Letters;
+use czwordle_core::statistics::Record;
+
+#[cfg(test)]
+mod tests;
+
+pub const CLEAR: &str = "\x1b[2J\x1b[H";
+pub const RESET: &str = "\x1b[0m";
+pub const TITLE: &str = "\x1b[1;32m";
+
+const GREEN: &str = "\x1b[1;97;42m";
+const YELLOW: &str = "\x1b[1;30;43m";
+const GRAY: &str = "\x1b[1;97;100m";
+const RED: &str = "\x1b[1;97;41m";
+const UNUSED: &str = "\x1b[30;47m";
+const EMPTY: &str = "\x1b[2m";
+
+// Every tile is three columns wide, the boards are placed side by side while they fit.
+const TILE_WIDTH: usize = 3;
+const TERMINAL_WIDTH: usize = 80;
+const BOARD_SPACING: usize = 3;
+
+// Width of the longest bar of the guess distribution.
+const BAR_WIDTH: u32 = 40;
+
+pub fn draw_guess(guess: &Guess) -> String {
+    (0_u32..)
+        .zip(guess.word.chars())
+        .map(|(i, c)| {
+            if guess.green_positions.contains(&i) {
+                draw_tile(c, GREEN)
+            } else if guess.yellow_positions.contains(&i) {
+                draw_tile(c, YELLOW)
+            } else {
+                draw_tile(c, GRAY)
+            }
+        })
+        .collect()
+}
+
+// All boards with their guesses and empty rows, the missed words of lost boards are shown in red.
+pub fn draw_boards(boards: &Boards) -> String {
+    let board_width: usize = boards.get_word_length() as usize * TILE_WIDTH;
+    let boards_per_row: usize =
+        ((TERMINAL_WIDTH + BOARD_SPACING) / (board_width + BOARD_SPACING)).max(1);
+
+    let mut result: Vec<String> = Vec::new();
+    for games in boards.get_games().chunks(boards_per_row) {
+        let columns: Vec<Vec<String>> = games.iter().map(draw_board).collect();
+        let height: usize = columns.iter().map(|lines| lines.len()).max().unwrap_or(0);
+
+        for i in 0..height {
+            let line: Vec<String> = columns
+                .iter()
+                .map(|lines| {
+                    lines
+                        .get(i)
+                        .cloned()
+                        .unwrap_or_else(|| " ".repeat(board_width))
+                })
+                .collect();
+            result.push(line.join(&" ".repeat(BOARD_SPACING)));
+        }
+        result.push(String::new());
+    }
+
+    result.join("\n")
+}
+
+// The letters of the layout colored by the guesses, the action keys are left out as the words are typed.
+// With more boards the colors would differ between them, only the used letters are marked then.
+// The solved boards get no more guesses, so the used letters are collected from all of them.
+pub fn draw_keyboard(rows: &[Vec<KeyKind>], boards: &Boards) -> String {
+    let games: &Vec<Game> = boards.get_games();
+    let used_letters: HashSet<char> = games
+        .iter()
+        .flat_map(|game| game.get_letters().get_used_letters().iter().copied())
+        .collect();
+    let letter_rows: Vec<Vec<char>> = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .filter_map(|kind| match kind {
+                    KeyKind::Letter(c) => Some(*c),
+                    _ => None,
+                })
+                .collect::<Vec<char>>()
+        })
+        .filter(|row| !row.is_empty())
+        .collect();
+    let widest: usize = letter_rows.iter().map(|row| row.len()).max().unwrap_or(0);
+
+    letter_rows
+        .iter()
+        .map(|row| {
+            let mut line: String = " ".repeat((widest - row.len()) * TILE_WIDTH / 2);
+            for c in row {
+                let color: &str = if games.len() == 1 {
+                    get_key_color(games[0].get_letters(), *c)
+                } else if used_letters.contains(c) {
+                    GRAY
+                } else {
+                    UNUSED
+                };
+                line.push_str(&draw_tile(*c, color));
+            }
+            line
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn draw_statistics(word_length: u32, attempts: u32, record: &Record) -> String {
+    let mut result = format!(
+        "{}STATISTICS {}/{}{}\n\nPLAYED {}\nWIN % {}\nSTREAK {}\nMAX STREAK {}\n\n",
+        TITLE,
+        word_length,
+        attempts,
+        RESET,
+        record.played,
+        record.get_win_percentage(),
+        record.current_streak,
+        record.max_streak
+    );
+
+    let largest: u32 = *record.guess_distribution.iter().max().unwrap_or(&0);
+    for (i, count) in (1_u32..).zip(&record.guess_distribution) {
+        let bar_length: u32 = if largest > 0 {
+            (count * BAR_WIDTH).div_ceil(largest)
+        } else {
+            0
+        };
+        result.push_str(&format!(
+            "{:>2} {}{:<width$}{}\n",
+            i,
+            UNUSED,
+            format!(" {}", count),
+            RESET,
+            width = bar_length.max(3) as usize
+        ));
+    }
+
+    result
+}
+
+// One line per try, the rows not played yet are dotted.
+fn draw_board(game: &Game) -> Vec<String> {
+    let word_length: usize = game.get_word_length() as usize;
+
+    let mut lines: Vec<String> = game.get_guesses().iter().map(draw_guess).collect();
+    while lines.len() < game.get_maximum_tries() as usize {
+        lines.push(draw_tile('·', EMPTY).repeat(word_length));
+    }
+
+    if game.get_game_state() == GameState::Lose {
+        lines.push(
+            game.get_correct_word()
+                .chars()
+                .map(|c| draw_tile(c, RED))
+                .collect(),
+        );
+    }
+
+    lines
+}
+
+fn draw_tile(letter: char, color: &str) -> String {
+    format!("{} {} {}", color, letter, RESET)
+}
+
+fn get_key_color(letters: &Letters, letter: char) -> &'static str {
+    if letters.get_green_letters().contains(&letter) {
+        GREEN
+    } else if letters.get_yellow_letters().contains(&letter) {
+        YELLOW
+    } else if letters.get_used_letters().contains(&letter) {
+        GRAY
+    } else {
+        UNUSED
+    }
+}
@@ -0,0 +1,90 @@
+use czwordle_core::boards::Boards;
+use czwordle_core::dictionary::Dictionary;
+use czwordle_core::game::Game;
+use czwordle_core::layout::KeyKind;
+
+use super::draw_boards;
+use super::draw_guess;
+use super::draw_keyboard;
+
+fn make_boards<'d>(d: &'d Dictionary, words: &[&str], maximum_tries: u32) -> Boards<'d> {
+    let games: Vec<Game> = words
+        .iter()
+        .map(|word| Game::new_with_answer(maximum_tries, word, d.get_word_list(4).unwrap()))
+        .collect();
+    Boards::new(games)
+}
+
+#[test]
+fn guess_tiles() {
+    let d = Dictionary::new("pivo\nkolo", "");
+    let mut game = Game::new_with_answer(6, "kolo", d.get_word_list(4).unwrap());
+    let guess = game.submit_guess("pivo").unwrap();
+
+    assert_eq!(
+        draw_guess(&guess),
+        "\x1b[1;97;100m P \x1b[0m\x1b[1;97;100m I \x1b[0m\x1b[1;97;100m V \x1b[0m\x1b[1;97;42m O \x1b[0m"
+    );
+}
+
+#[test]
+fn boards_side_by_side() {
+    let d = Dictionary::new("pivo\nkolo\nauto", "");
+    let mut boards = make_boards(&d, &["kolo", "auto"], 2);
+    boards.submit_guess("pivo").unwrap();
+    boards.submit_guess("pivo").unwrap();
+
+    // both boards are lost, their answers are added under them
+    let text = draw_boards(&boards);
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].contains("   \x1b[1;97;100m P "));
+    assert!(lines[2].contains(" K \x1b[0m\x1b[1;97;41m O "));
+    assert!(lines[2].contains("\x1b[1;97;41m A "));
+}
+
+#[test]
+fn keyboard_colors() {
+    let d = Dictionary::new("pivo\nkolo", "");
+    let mut boards = make_boards(&d, &["kolo"], 6);
+    boards.submit_guess("pivo").unwrap();
+
+    let rows = vec![
+        vec![
+            KeyKind::Letter('P'),
+            KeyKind::Letter('O'),
+            KeyKind::Letter('K'),
+        ],
+        vec![KeyKind::Enter, KeyKind::Letter('L'), KeyKind::Backspace],
+    ];
+    let text = draw_keyboard(&rows, &boards);
+    let lines: Vec<&str> = text.lines().collect();
+
+    assert_eq!(
+        lines[0],
+        "\x1b[1;97;100m P \x1b[0m\x1b[1;97;42m O \x1b[0m\x1b[30;47m K \x1b[0m"
+    );
+    // the shorter row is indented by a tile, the action keys are left out
+    assert_eq!(lines[1], "   \x1b[30;47m L \x1b[0m");
+}
+
+#[test]
+fn keyboard_with_a_solved_board() {
+    let d = Dictionary::new("pivo\nkolo\nauto", "");
+    let mut boards = make_boards(&d, &["kolo", "auto"], 6);
+    boards.submit_guess("kolo").unwrap();
+    // the first board is solved and does not get this guess
+    boards.submit_guess("pivo").unwrap();
+
+    let rows = vec![vec![
+        KeyKind::Letter('P'),
+        KeyKind::Letter('K'),
+        KeyKind::Letter('A'),
+    ]];
+    let text = draw_keyboard(&rows, &boards);
+
+    assert_eq!(
+        text,
+        "\x1b[1;97;100m P \x1b[0m\x1b[1;97;100m K \x1b[0m\x1b[30;47m A \x1b[0m"
+    );
+}
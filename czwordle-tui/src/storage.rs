@@ -0,0 +1,14 @@
+// Key-value storage in the user's config directory, the same files as the native window version uses,
+// so the settings, statistics and a saved game are shared between the two.
+
+use czwordle_core::storage;
+
+pub fn load(key: &str) -> Option<String> {
+    storage::load(&dirs::config_dir()?, key)
+}
+
+pub fn save(key: &str, value: &str) {
+    if let Some(directory) = dirs::config_dir() {
+        storage::save(&directory, key, value);
+    }
+}
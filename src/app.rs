@@ -1,22 +1,52 @@
+use std::collections::BTreeMap;
+
 use macroquad::{
+    file::load_string,
+    miniquad,
     prelude::{get_char_pressed, is_key_pressed, is_key_released, KeyCode},
     text::TextParams,
     texture::Texture2D,
+    window::get_internal_gl,
 };
 
-use crate::dictionary::Dictionary;
-
-use crate::game::Game;
-use crate::game::GameState;
+use czwordle_core::alphabet::Alphabet;
+use czwordle_core::catalog;
+use czwordle_core::catalog::DictionaryFile;
+use czwordle_core::daily;
+use czwordle_core::dictionary::Dictionary;
+
+use czwordle_core::boards::Boards;
+use czwordle_core::game::get_error_message;
+use czwordle_core::game::Game;
+use czwordle_core::game::GameState;
+use czwordle_core::layout;
+use czwordle_core::layout::KeyKind;
+use czwordle_core::layout::KeyboardLayout;
+use czwordle_core::random::SeededRandom;
+use czwordle_core::session;
+use czwordle_core::session::DictionaryTexts;
+use czwordle_core::settings;
+use czwordle_core::settings::Settings;
+use czwordle_core::solver::Hints;
+use czwordle_core::statistics::Statistics;
 
 use crate::gui::graphics::Graphics;
+use crate::gui::keyboard::Keyboard;
 use crate::gui::menu::Menu;
+use crate::gui::menu::MenuInput;
+use crate::storage;
+
+// How many best guesses the hint shows.
+const HINT_SUGGESTIONS: usize = 3;
 
 #[derive(PartialEq, Eq, Debug, Copy, Clone)]
 pub enum ApplicationState {
     Menu,
     NewGame,
+    Daily,
+    Continue,
     Game,
+    Statistics,
     Quit,
 }
 
@@ -24,19 +54,31 @@ pub enum ApplicationState {
 pub struct MainMenuData {
     pub state: ApplicationState,
     pub settings: Settings,
+    pub puzzle_number: u32,
+    pub daily_played: bool,
 }
 
 #[derive(Debug, Copy, Clone)]
-pub struct Settings {
-    pub word_length: u32,
-    pub attempts: u32,
+pub struct GameOverMenuData {
+    pub state: ApplicationState,
+    pub share: bool,
+    pub is_shared: bool,
 }
 
 pub struct App<'s> {
     pub settings: Settings,
 
     gui: Graphics,
-    text_file: &'s str,
+    builtin_answers_file: &'s str,
+    builtin_guesses_file: &'s str,
+    dictionaries: Vec<DictionaryFile>,
+    loaded_dictionary: Option<usize>,
+    dictionary_texts: DictionaryTexts,
+    layouts: Vec<KeyboardLayout>,
+    last_daily_puzzle: Option<u32>,
+    statistics: Statistics,
+    random: SeededRandom,
+    hints: Hints,
 
     word: String,
 }
@@ -44,59 +86,203 @@ pub struct App<'s> {
 enum InputResult {
     Incomplete,
     Entered,
+    Hint,
     Quit,
 }
 
 impl<'s, 'd, 'm, 'n> App<'s> {
     pub fn new(
-        text_file: &'s str,
+        builtin_answers_file: &'s str,
+        builtin_guesses_file: &'s str,
         font: TextParams,
         logo: Texture2D,
         box_textures: Vec<Texture2D>,
+        seed: u64,
     ) -> App<'s> {
         App {
-            text_file,
+            builtin_answers_file,
+            builtin_guesses_file,
+            dictionaries: Vec::new(),
+            loaded_dictionary: None,
+            dictionary_texts: DictionaryTexts::new(builtin_answers_file, builtin_guesses_file),
+            layouts: vec![KeyboardLayout::default()],
+            last_daily_puzzle: storage::load("daily").and_then(|text| text.trim().parse().ok()),
+            statistics: Statistics::from_text(&storage::load("statistics").unwrap_or_default()),
             gui: Graphics::new(font, logo, box_textures),
-            settings: Settings {
-                word_length: 5,
-                attempts: 6,
-            },
+            settings: Settings::from_text(&storage::load("settings").unwrap_or_default()),
+            random: SeededRandom::new(seed),
+            hints: Hints::new(),
             word: String::new(),
         }
     }
 
     pub fn make_dictionary(&self) -> Dictionary {
-        Dictionary::new(self.text_file, self.settings.word_length)
+        session::make_dictionary(&self.dictionary_texts)
+    }
+
+    pub async fn load_catalog(&mut self) {
+        match load_string(catalog::CATALOG_PATH).await {
+            Ok(text_file) => self.dictionaries = catalog::parse_catalog(&text_file),
+            Err(_) => println!("ERROR: could not load {}", catalog::CATALOG_PATH),
+        }
+    }
+
+    pub async fn load_layouts(&mut self) {
+        match load_string(layout::LAYOUTS_PATH).await {
+            Ok(text_file) => {
+                let layouts = layout::parse_layouts(&text_file);
+                if !layouts.is_empty() {
+                    self.layouts = layouts;
+                }
+            }
+            Err(_) => println!("ERROR: could not load {}", layout::LAYOUTS_PATH),
+        }
+    }
+
+    fn get_layout_names(&self) -> Vec<String> {
+        let mut result: Vec<String> = self.layouts.iter().map(|l| l.name.clone()).collect();
+        result.push(KeyboardLayout::from_alphabet(&self.dictionary_texts.get_alphabet()).name);
+        result
+    }
+
+    // Loads the dictionary files chosen in the settings, returns true when the word lists changed.
+    pub async fn load_dictionary(&mut self) -> bool {
+        let selected: usize = self.settings.dictionary;
+        if selected >= self.dictionaries.len() || self.loaded_dictionary == Some(selected) {
+            return false;
+        }
+
+        let file: DictionaryFile = self.dictionaries[selected].clone();
+        self.loaded_dictionary = Some(selected);
+
+        let answers = load_string(&file.answers_path).await;
+        let guesses = match &file.guesses_path {
+            Some(path) => load_string(path).await,
+            None => Ok(String::new()),
+        };
+        let affixes = match &file.affix_path {
+            Some(path) => load_string(path).await,
+            None => Ok(String::new()),
+        };
+        let alphabet = match &file.alphabet_path {
+            Some(path) => load_string(path).await.map(Some),
+            None => Ok(None),
+        };
+
+        self.dictionary_texts = match (answers, guesses, affixes, alphabet) {
+            (Ok(answers), Ok(guesses), Ok(affixes), Ok(alphabet)) => DictionaryTexts {
+                answers,
+                guesses,
+                filter: file.filter,
+                affixes,
+                alphabet,
+            },
+            _ => {
+                println!("ERROR: could not load dictionary {}", file.name);
+                DictionaryTexts::new(self.builtin_answers_file, self.builtin_guesses_file)
+            }
+        };
+
+        true
     }
 
-    pub async fn run_menu_loop(&mut self) -> ApplicationState {
-        let mut main_menu = App::make_main_menu(self.settings.attempts, self.settings.word_length);
+    pub async fn run_menu_loop(&mut self, dictionary: &mut Dictionary) -> ApplicationState {
+        let puzzle_number = daily::puzzle_number(miniquad::date::now());
+        let initial_settings: Settings = self.settings;
+        let mut main_menu = App::make_main_menu(
+            self.settings,
+            puzzle_number,
+            self.last_daily_puzzle == Some(puzzle_number),
+            App::load_saved_game(dictionary).is_some(),
+            self.dictionaries.iter().map(|d| d.name.clone()).collect(),
+            self.get_layout_names(),
+            session::get_word_counts(dictionary),
+        );
         loop {
             let y_start: f32 = self.gui.draw_menu_header();
+            self.gui.draw_seed(self.random.get_seed());
             let result = main_menu.run(y_start, &mut self.gui);
             self.settings = result.settings;
 
             macroquad::window::next_frame().await;
 
+            if self.load_dictionary().await {
+                *dictionary = self.make_dictionary();
+                self.hints.clear();
+
+                let position: u32 = main_menu.get_position();
+                main_menu = App::make_main_menu(
+                    self.settings,
+                    puzzle_number,
+                    self.last_daily_puzzle == Some(puzzle_number),
+                    App::load_saved_game(dictionary).is_some(),
+                    self.dictionaries.iter().map(|d| d.name.clone()).collect(),
+                    self.get_layout_names(),
+                    session::get_word_counts(dictionary),
+                );
+                main_menu.set_position(position);
+            }
+
             if result.state != ApplicationState::Menu {
+                if self.settings != initial_settings {
+                    storage::save("settings", &self.settings.to_text());
+                }
                 return result.state;
             }
         }
     }
 
-    pub async fn run_game_loop(&mut self, dictionary: &'d mut Dictionary) -> ApplicationState {
-        let mut game: Game = self.make_game(dictionary);
+    // Starts the game chosen in the menu, a new one, the daily one or the saved one.
+    pub async fn run_game_loop(
+        &mut self,
+        dictionary: &'d Dictionary,
+        application_state: ApplicationState,
+    ) -> ApplicationState {
+        let boards = match application_state {
+            ApplicationState::Continue => {
+                App::load_saved_game(dictionary).or_else(|| self.make_boards(dictionary, false))
+            }
+            _ => self.make_boards(dictionary, application_state == ApplicationState::Daily),
+        };
+        let mut boards: Boards = match boards {
+            Some(boards) => boards,
+            None => return ApplicationState::Menu,
+        };
+        let saved_game: String = storage::load("game").unwrap_or_default();
+        if application_state != ApplicationState::Continue
+            && session::record_abandoned_game(&mut self.statistics, &saved_game, dictionary)
+        {
+            storage::save("statistics", &self.statistics.to_text());
+        }
+        if let Some(puzzle_number) = boards.get_puzzle_number() {
+            self.last_daily_puzzle = Some(puzzle_number);
+            storage::save("daily", &puzzle_number.to_string());
+        }
+        storage::save("game", &boards.to_text());
+        let mut is_recorded = false;
+
         let mut game_over_menu = App::make_game_over_menu();
 
         loop {
-            let app_state: ApplicationState = match game.get_game_state() {
-                GameState::Ongoing(_) => self.run_game_frame(&mut game),
-                GameState::Win(_) => self.run_win_frame(&mut game, &mut game_over_menu),
-                GameState::Lose => self.run_loss_frame(&mut game, &mut game_over_menu),
+            let app_state: ApplicationState = match boards.get_game_state() {
+                GameState::Ongoing(_) => self.run_game_frame(&mut boards),
+                _ if boards.get_board_count() > 1 => {
+                    self.run_boards_over_frame(&boards, &mut game_over_menu)
+                }
+                GameState::Win(_) => self.run_win_frame(&boards, &mut game_over_menu),
+                GameState::Lose => self.run_loss_frame(&boards, &mut game_over_menu),
             };
 
             macroquad::window::next_frame().await;
 
+            if !is_recorded && !matches!(boards.get_game_state(), GameState::Ongoing(_)) {
+                if session::record_game(&mut self.statistics, &boards) {
+                    storage::save("statistics", &self.statistics.to_text());
+                }
+                storage::save("game", "");
+                is_recorded = true;
+            }
+
             if app_state != ApplicationState::Game {
                 while get_char_pressed().is_some() {}
                 return app_state;
@@ -104,119 +290,349 @@ impl<'s, 'd, 'm, 'n> App<'s> {
         }
     }
 
-    fn make_main_menu(attempts: u32, word_length: u32) -> Menu<'m, MainMenuData> {
-        let item_callback = |data: &mut MainMenuData, items: &Vec<String>| -> Vec<String> {
-            let mut retval: Vec<String> = Vec::new();
-            retval.push(items[0].to_string());
-            retval.push(format!("{} {}", data.settings.attempts, items[1]));
-            retval.push(format!("{} {}", data.settings.word_length, items[2]));
-            retval.push(items[3].to_string());
+    pub async fn run_statistics_loop(&mut self) -> ApplicationState {
+        let record = self
+            .statistics
+            .get_record(self.settings.word_length, self.settings.attempts);
+
+        let mut menu = Menu::new(
+            Vec::from(["MENU".to_string()]),
+            ApplicationState::Statistics,
+            |_: &mut u32, data: &mut ApplicationState, input: MenuInput| {
+                if input == MenuInput::Select || input == MenuInput::Back {
+                    *data = ApplicationState::Menu;
+                }
+            },
+        );
+
+        loop {
+            let y_start: f32 = self.gui.draw_statistics(
+                self.settings.word_length,
+                self.settings.attempts,
+                &record,
+            );
+            let result = menu.run(y_start, &mut self.gui);
+
+            macroquad::window::next_frame().await;
+
+            if result != ApplicationState::Statistics {
+                return result;
+            }
+        }
+    }
+
+    fn make_main_menu(
+        settings: Settings,
+        puzzle_number: u32,
+        daily_played: bool,
+        has_saved_game: bool,
+        dictionary_names: Vec<String>,
+        layout_names: Vec<String>,
+        word_counts: BTreeMap<u32, usize>,
+    ) -> Menu<'m, MainMenuData> {
+        let dictionary_count: usize = dictionary_names.len();
+        let layout_count: usize = layout_names.len();
+        // the CONTINUE item is shown only with a saved game, the other items move down
+        let first_item: u32 = if has_saved_game { 1 } else { 0 };
+
+        let item_word_counts: BTreeMap<u32, usize> = word_counts.clone();
+        let item_callback = move |data: &mut MainMenuData, items: &Vec<String>| -> Vec<String> {
+            let (saved_game_items, items) = items.split_at(first_item as usize);
+            let mut retval: Vec<String> = saved_game_items.to_vec();
+            let word_count: usize = *item_word_counts
+                .get(&data.settings.word_length)
+                .unwrap_or(&0);
+            if word_count > 0 {
+                retval.push(items[0].to_string());
+            } else {
+                retval.push(format!("{} (UNAVAILABLE)", items[0]));
+            }
+            if !item_word_counts.contains_key(&daily::WORD_LENGTH) {
+                retval.push(format!("{} (UNAVAILABLE)", items[1]));
+            } else if data.daily_played {
+                retval.push(format!("{} #{} (DONE)", items[1], data.puzzle_number));
+            } else {
+                retval.push(format!("{} #{}", items[1], data.puzzle_number));
+            }
+            retval.push(format!("{} {}", data.settings.attempts, items[2]));
+            retval.push(format!(
+                "{} {} ({} WORDS)",
+                data.settings.word_length, items[3], word_count
+            ));
+            retval.push(format!(
+                "{} {} ({} ATTEMPTS)",
+                items[4],
+                data.settings.boards,
+                data.settings.attempts + data.settings.boards - 1
+            ));
+            retval.push(format!(
+                "{} {}",
+                items[5],
+                if data.settings.hard_mode { "ON" } else { "OFF" }
+            ));
+            retval.push(format!(
+                "{} {}",
+                items[6],
+                if data.settings.easy_mode { "ON" } else { "OFF" }
+            ));
+            if data.settings.absurdle && data.settings.boards > 1 {
+                retval.push(format!("{} ON (ONE BOARD ONLY)", items[7]));
+            } else if data.settings.absurdle {
+                retval.push(format!("{} ON", items[7]));
+            } else {
+                retval.push(format!("{} OFF", items[7]));
+            }
+            retval.push(format!(
+                "{} {}",
+                items[8],
+                dictionary_names
+                    .get(data.settings.dictionary)
+                    .map_or("BUILT-IN", |name| name.as_str())
+            ));
+            retval.push(format!(
+                "{} {}",
+                items[9],
+                layout_names
+                    .get(data.settings.layout)
+                    .map_or("QWERTY", |name| name.as_str())
+            ));
+            retval.push(items[10].to_string());
+            retval.push(items[11].to_string());
             retval
         };
 
-        let callback = |position: &mut u32, data: &mut MainMenuData| {
-            if is_key_pressed(KeyCode::Enter) {
-                match *position {
-                    0 => data.state = ApplicationState::NewGame,
-                    3 => data.state = ApplicationState::Quit,
+        let callback = move |position: &mut u32, data: &mut MainMenuData, input: MenuInput| {
+            let item: Option<u32> = position.checked_sub(first_item);
+            // selecting a value steps it up, so that a tap anywhere on the item changes it
+            let input: MenuInput = match (input, item) {
+                (MenuInput::Select, Some(2 | 3 | 8 | 9)) => MenuInput::Right,
+                _ => input,
+            };
+
+            if input == MenuInput::Select {
+                match item {
+                    None => data.state = ApplicationState::Continue,
+                    Some(0) if word_counts.contains_key(&data.settings.word_length) => {
+                        data.state = ApplicationState::NewGame
+                    }
+                    Some(1)
+                        if !data.daily_played && word_counts.contains_key(&daily::WORD_LENGTH) =>
+                    {
+                        data.state = ApplicationState::Daily
+                    }
+                    Some(4) => {
+                        data.settings.boards = settings::cycle_board_count(data.settings.boards, 1)
+                    }
+                    Some(5) => data.settings.hard_mode = !data.settings.hard_mode,
+                    Some(6) => data.settings.easy_mode = !data.settings.easy_mode,
+                    Some(7) => data.settings.absurdle = !data.settings.absurdle,
+                    Some(10) => data.state = ApplicationState::Statistics,
+                    Some(11) => data.state = ApplicationState::Quit,
                     _ => {}
                 }
-            } else if is_key_pressed(KeyCode::Escape) {
+            } else if input == MenuInput::Back {
                 data.state = ApplicationState::Quit;
-            } else if is_key_pressed(KeyCode::Left) {
-                match *position {
-                    1 => data.settings.attempts -= 1,
-                    2 => data.settings.word_length -= 1,
+            } else if input == MenuInput::Left {
+                match item {
+                    Some(2) if data.settings.attempts > *settings::ATTEMPTS_RANGE.start() => {
+                        data.settings.attempts -= 1
+                    }
+                    Some(3) => {
+                        if let Some((word_length, _)) =
+                            word_counts.range(..data.settings.word_length).next_back()
+                        {
+                            data.settings.word_length = *word_length;
+                        }
+                    }
+                    Some(4) => {
+                        data.settings.boards = settings::cycle_board_count(
+                            data.settings.boards,
+                            settings::BOARD_COUNTS.len() - 1,
+                        )
+                    }
+                    Some(5) => data.settings.hard_mode = !data.settings.hard_mode,
+                    Some(6) => data.settings.easy_mode = !data.settings.easy_mode,
+                    Some(7) => data.settings.absurdle = !data.settings.absurdle,
+                    Some(8) if dictionary_count > 0 => {
+                        data.settings.dictionary =
+                            (data.settings.dictionary + dictionary_count - 1) % dictionary_count
+                    }
+                    Some(9) if layout_count > 0 => {
+                        data.settings.layout =
+                            (data.settings.layout + layout_count - 1) % layout_count
+                    }
                     _ => {}
                 }
-            } else if is_key_pressed(KeyCode::Right) {
-                match *position {
-                    1 => data.settings.attempts += 1,
-                    2 => data.settings.word_length += 1,
+            } else if input == MenuInput::Right {
+                match item {
+                    Some(2) if data.settings.attempts < *settings::ATTEMPTS_RANGE.end() => {
+                        data.settings.attempts += 1
+                    }
+                    Some(3) => {
+                        if let Some((word_length, _)) =
+                            word_counts.range(data.settings.word_length + 1..).next()
+                        {
+                            data.settings.word_length = *word_length;
+                        }
+                    }
+                    Some(4) => {
+                        data.settings.boards = settings::cycle_board_count(data.settings.boards, 1)
+                    }
+                    Some(5) => data.settings.hard_mode = !data.settings.hard_mode,
+                    Some(6) => data.settings.easy_mode = !data.settings.easy_mode,
+                    Some(7) => data.settings.absurdle = !data.settings.absurdle,
+                    Some(8) if dictionary_count > 0 => {
+                        data.settings.dictionary = (data.settings.dictionary + 1) % dictionary_count
+                    }
+                    Some(9) if layout_count > 0 => {
+                        data.settings.layout = (data.settings.layout + 1) % layout_count
+                    }
                     _ => {}
                 }
             }
         };
 
+        let mut items: Vec<String> = Vec::new();
+        if has_saved_game {
+            items.push("CONTINUE".to_string());
+        }
+        items.extend([
+            "NEW GAME".to_string(),
+            "DAILY".to_string(),
+            "ATTEMPTS".to_string(),
+            "WORD LENGTH".to_string(),
+            "BOARDS".to_string(),
+            "HARD MODE".to_string(),
+            "EASY MODE".to_string(),
+            "ABSURDLE".to_string(),
+            "DICTIONARY".to_string(),
+            "KEYBOARD".to_string(),
+            "STATISTICS".to_string(),
+            "QUIT".to_string(),
+        ]);
+
         Menu::new_with_items_callback(
-            Vec::from([
-                "NEW GAME".to_string(),
-                "ATTEMPTS".to_string(),
-                "WORD LENGTH".to_string(),
-                "QUIT".to_string(),
-            ]),
+            items,
             MainMenuData {
                 state: ApplicationState::Menu,
-                settings: Settings {
-                    attempts,
-                    word_length,
-                },
+                settings,
+                puzzle_number,
+                daily_played,
             },
             callback,
             item_callback,
         )
     }
 
-    fn make_game(&self, dictionary: &'d mut Dictionary) -> Game<'d> {
-        if dictionary.get_word_length() != self.settings.word_length {
-            *dictionary = Dictionary::new(self.text_file, self.settings.word_length)
-        }
+    fn load_saved_game(dictionary: &'d Dictionary) -> Option<Boards<'d>> {
+        session::load_saved_game(&storage::load("game")?, dictionary)
+    }
 
-        Game::new(self.settings.attempts, dictionary)
+    fn make_boards(&mut self, dictionary: &'d Dictionary, daily: bool) -> Option<Boards<'d>> {
+        session::make_boards(
+            &self.settings,
+            dictionary,
+            daily,
+            miniquad::date::now(),
+            &mut self.random,
+        )
     }
 
-    fn make_game_over_menu() -> Menu<'n, ApplicationState> {
-        let callback = |position: &mut u32, data: &mut ApplicationState| {
-            if is_key_pressed(KeyCode::Enter) {
+    fn make_game_over_menu() -> Menu<'n, GameOverMenuData> {
+        let item_callback = |data: &mut GameOverMenuData, items: &Vec<String>| -> Vec<String> {
+            let mut retval: Vec<String> = items.clone();
+            if data.is_shared {
+                retval[2] = format!("{} (COPIED)", items[2]);
+            }
+            retval
+        };
+
+        let callback = |position: &mut u32, data: &mut GameOverMenuData, input: MenuInput| {
+            data.share = false;
+
+            if input == MenuInput::Select {
                 match *position {
-                    0 => *data = ApplicationState::NewGame,
-                    1 => *data = ApplicationState::Menu,
+                    0 => data.state = ApplicationState::NewGame,
+                    1 => data.state = ApplicationState::Menu,
+                    2 => {
+                        data.share = true;
+                        data.is_shared = true;
+                    }
                     _ => {}
                 }
-            } else if is_key_pressed(KeyCode::Escape) {
-                *data = ApplicationState::Quit;
+            } else if input == MenuInput::Back {
+                data.state = ApplicationState::Quit;
             }
         };
 
-        Menu::new(
-            Vec::from(["NEW GAME".to_string(), "MENU".to_string()]),
-            ApplicationState::Game,
+        Menu::new_with_items_callback(
+            Vec::from([
+                "NEW GAME".to_string(),
+                "MENU".to_string(),
+                "SHARE".to_string(),
+            ]),
+            GameOverMenuData {
+                state: ApplicationState::Game,
+                share: false,
+                is_shared: false,
+            },
             callback,
+            item_callback,
         )
     }
 
-    fn run_game_frame(&mut self, game: &mut Game) -> ApplicationState {
-        assert!(matches!(game.get_game_state(), GameState::Ongoing { .. }));
+    fn run_game_frame(&mut self, boards: &mut Boards) -> ApplicationState {
+        assert!(matches!(boards.get_game_state(), GameState::Ongoing { .. }));
+
+        // the last layout choice is the alphabet itself
+        let alphabet: &Alphabet = boards.get_alphabet();
+        let alphabet_layout: KeyboardLayout;
+        let layout: &KeyboardLayout = match self.layouts.get(self.settings.layout) {
+            Some(layout) => layout,
+            None => {
+                alphabet_layout = KeyboardLayout::from_alphabet(alphabet);
+                &alphabet_layout
+            }
+        };
+        let keyboard: Keyboard = self.gui.get_keyboard(boards, layout, alphabet);
 
-        match self.handle_input() {
+        match self.handle_input(boards.get_word_length(), &keyboard, alphabet) {
             InputResult::Quit => {
                 return ApplicationState::Menu;
             }
-            InputResult::Entered => {
-                match game.submit_guess(self.word.as_str()) {
-                    Ok(_) => {}
-                    Err(_) => {
-                        println!("ERROR: incorrect word"); // TODO: GUI error message
-                    }
+            InputResult::Entered => match boards.submit_guess(self.word.as_str()) {
+                Ok(_) => {
+                    self.word.clear();
+                    storage::save("game", &boards.to_text());
                 }
-                self.word.clear();
-            }
+                Err(error) => {
+                    self.gui
+                        .show_toast(&get_error_message(&error, boards.get_word_length()));
+                    self.gui.shake_current_row();
+                }
+            },
+            InputResult::Hint => self
+                .gui
+                .show_hint(&self.hints.get_message(boards, HINT_SUGGESTIONS)),
             InputResult::Incomplete => {}
         }
 
-        self.gui.draw_game(&self.settings, game, &self.word);
+        self.gui.draw_game(boards, &self.word, &keyboard);
 
         ApplicationState::Game
     }
 
-    fn handle_input(&mut self) -> InputResult {
+    fn handle_input(
+        &mut self,
+        word_length: u32,
+        keyboard: &Keyboard,
+        alphabet: &Alphabet,
+    ) -> InputResult {
         if is_key_released(KeyCode::Escape) {
             return InputResult::Quit;
         }
 
-        if is_key_pressed(KeyCode::Enter)
-            && self.word.chars().count() == self.settings.word_length as usize
-        {
+        if is_key_pressed(KeyCode::Enter) {
             return InputResult::Entered;
         }
 
@@ -225,47 +641,95 @@ impl<'s, 'd, 'm, 'n> App<'s> {
             get_char_pressed();
         }
 
+        // characters outside of the alphabet are ignored
         while let Some(c) = get_char_pressed() {
-            match c {
-                '\u{00}'..='\u{1F}' => {
-                    continue;
-                }
-                _ => {
-                    if self.word.chars().count() < self.settings.word_length as usize {
-                        for char in c.to_uppercase() {
-                            self.word.push(char);
-                        }
-                    }
+            if let Some(letter) = alphabet.to_uppercase(c) {
+                self.push_letter(letter, word_length);
+            }
+        }
+
+        // clicks and taps on the on-screen keyboard
+        for key in keyboard.get_pressed_keys() {
+            match key {
+                KeyKind::Enter => return InputResult::Entered,
+                KeyKind::Hint => return InputResult::Hint,
+                KeyKind::Backspace => {
+                    self.word.pop();
                 }
+                KeyKind::Letter(c) => self.push_letter(c, word_length),
             }
         }
 
         InputResult::Incomplete
     }
 
+    fn push_letter(&mut self, letter: char, word_length: u32) {
+        if self.word.chars().count() < word_length as usize {
+            self.word.push(letter);
+        }
+    }
+
     fn run_win_frame(
         &mut self,
-        game: &mut Game,
-        menu: &mut Menu<ApplicationState>,
+        boards: &Boards,
+        menu: &mut Menu<GameOverMenuData>,
     ) -> ApplicationState {
+        let game: &Game = &boards.get_games()[0];
         let y_start: f32 = self
             .gui
-            .draw_win(self.settings.word_length, game.get_guesses());
+            .draw_win(game.get_word_length(), game.get_guesses());
+        if let Some(puzzle_number) = game.get_puzzle_number() {
+            self.gui.draw_puzzle_number(puzzle_number);
+        }
 
-        menu.run(y_start, &mut self.gui)
+        self.run_game_over_menu(y_start, boards, menu)
     }
 
     fn run_loss_frame(
         &mut self,
-        game: &mut Game,
-        menu: &mut Menu<ApplicationState>,
+        boards: &Boards,
+        menu: &mut Menu<GameOverMenuData>,
     ) -> ApplicationState {
+        let game: &Game = &boards.get_games()[0];
         let y_start: f32 = self.gui.draw_loss(
-            self.settings.word_length,
+            game.get_word_length(),
             game.get_guesses(),
             &game.get_correct_word(),
         );
+        if let Some(puzzle_number) = game.get_puzzle_number() {
+            self.gui.draw_puzzle_number(puzzle_number);
+        }
 
-        menu.run(y_start, &mut self.gui)
+        self.run_game_over_menu(y_start, boards, menu)
     }
+
+    fn run_boards_over_frame(
+        &mut self,
+        boards: &Boards,
+        menu: &mut Menu<GameOverMenuData>,
+    ) -> ApplicationState {
+        let y_start: f32 = self.gui.draw_boards_over(boards);
+
+        self.run_game_over_menu(y_start, boards, menu)
+    }
+
+    fn run_game_over_menu(
+        &mut self,
+        y_start: f32,
+        boards: &Boards,
+        menu: &mut Menu<GameOverMenuData>,
+    ) -> ApplicationState {
+        let result = menu.run(y_start, &mut self.gui);
+
+        if result.share {
+            copy_to_clipboard(&boards.make_share_grid());
+        }
+
+        result.state
+    }
+}
+
+fn copy_to_clipboard(text: &str) {
+    let gl = unsafe { get_internal_gl() };
+    gl.quad_context.clipboard_set(text);
 }
@@ -0,0 +1,216 @@
+// Plays every answer of a dictionary with a chosen strategy and reports how well it does.
+// Runs without a window, e.g. `cargo run --release --bin czwordle-bench -- --length 5 --strategy solver`.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::process;
+
+use czwordle_core::alphabet::Alphabet;
+use czwordle_core::dictionary::Dictionary;
+use czwordle_core::game::Game;
+use czwordle_core::game::GameState;
+use czwordle_core::solver::Solver;
+
+const USAGE: &str = "usage: czwordle-bench [--answers FILE] [--guesses FILE] [--alphabet FILE] \
+                     [--length N] [--attempts N] [--strategy solver|first]";
+
+// Width of the longest bar of the guess distribution.
+const BAR_WIDTH: u32 = 40;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Strategy {
+    // the guess with the most expected information
+    Solver,
+    // the first word that can still be the answer
+    First,
+}
+
+struct Options {
+    answers_path: String,
+    guesses_path: Option<String>,
+    alphabet_path: Option<String>,
+    word_length: u32,
+    attempts: u32,
+    strategy: Strategy,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = match parse_options(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            println!("ERROR: {}", message);
+            println!("{}", USAGE);
+            process::exit(1);
+        }
+    };
+
+    let answers: String = read_file(&options.answers_path);
+    let guesses: String = options
+        .guesses_path
+        .as_deref()
+        .map_or(String::new(), read_file);
+
+    let alphabet: Alphabet = options
+        .alphabet_path
+        .as_deref()
+        .map_or(Alphabet::default(), |path| Alphabet::new(&read_file(path)));
+    let dictionary = Dictionary::new_with_alphabet(&answers, &guesses, alphabet);
+
+    let word_list = match dictionary.get_word_list(options.word_length) {
+        Some(word_list) => word_list,
+        None => {
+            println!(
+                "ERROR: {} has no words of length {}",
+                options.answers_path, options.word_length
+            );
+            process::exit(1);
+        }
+    };
+
+    // the first guess is the same for every answer, it is worked out only once
+    let first_game =
+        Game::new_with_answer(options.attempts, &word_list.get_answers()[0], word_list);
+    let first_guess: String = next_guess(&first_game, options.strategy);
+
+    let mut distribution: BTreeMap<u32, u32> =
+        (1..=options.attempts).map(|tries| (tries, 0)).collect();
+    let mut losses: u32 = 0;
+    for answer in word_list.get_answers() {
+        let mut game = Game::new_with_answer(options.attempts, answer, word_list);
+        match play(&mut game, options.strategy, &first_guess) {
+            GameState::Win(tries) => *distribution.entry(tries).or_insert(0) += 1,
+            _ => losses += 1,
+        }
+    }
+
+    print_report(&options, &distribution, losses);
+}
+
+fn parse_options(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        answers_path: "data/dictionary.txt".to_string(),
+        guesses_path: Some("data/guesses.txt".to_string()),
+        alphabet_path: None,
+        word_length: 5,
+        attempts: 6,
+        strategy: Strategy::Solver,
+    };
+
+    let mut args = args.iter();
+    while let Some(name) = args.next() {
+        let value: &String = args
+            .next()
+            .ok_or_else(|| format!("{} needs a value", name))?;
+
+        match name.as_str() {
+            "--answers" => options.answers_path = value.clone(),
+            "--guesses" => options.guesses_path = Some(value.clone()),
+            "--alphabet" => options.alphabet_path = Some(value.clone()),
+            "--length" => options.word_length = parse_number(name, value)?,
+            "--attempts" => options.attempts = parse_number(name, value)?,
+            "--strategy" => {
+                options.strategy = match value.as_str() {
+                    "solver" => Strategy::Solver,
+                    "first" => Strategy::First,
+                    _ => return Err(format!("unknown strategy {}", value)),
+                }
+            }
+            _ => return Err(format!("unknown option {}", name)),
+        }
+    }
+
+    if options.attempts == 0 {
+        return Err("--attempts must be at least 1".to_string());
+    }
+
+    Ok(options)
+}
+
+fn parse_number(name: &str, value: &str) -> Result<u32, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{} needs a number, not {}", name, value))
+}
+
+fn read_file(path: &str) -> String {
+    match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(error) => {
+            println!("ERROR: could not read {}: {}", path, error);
+            process::exit(1);
+        }
+    }
+}
+
+fn play(game: &mut Game, strategy: Strategy, first_guess: &str) -> GameState {
+    loop {
+        let guess: String = match game.get_game_state() {
+            GameState::Ongoing(0) => first_guess.to_string(),
+            GameState::Ongoing(_) => next_guess(game, strategy),
+            game_state => return game_state,
+        };
+
+        // the strategies only guess words that can be the answer, those are always accepted
+        game.submit_guess(&guess)
+            .expect("a candidate is always a valid guess");
+    }
+}
+
+fn next_guess(game: &Game, strategy: Strategy) -> String {
+    let solver = Solver::new(game);
+    match strategy {
+        Strategy::Solver => solver.suggest(1).remove(0).word,
+        Strategy::First => solver.get_candidates()[0].clone(),
+    }
+}
+
+fn print_report(options: &Options, distribution: &BTreeMap<u32, u32>, losses: u32) {
+    let wins: u32 = distribution.values().sum();
+    let games: u32 = wins + losses;
+    let total_tries: u32 = distribution
+        .iter()
+        .map(|(tries, count)| tries * count)
+        .sum();
+
+    println!(
+        "strategy {:?}, {} letters, {} attempts, {} answers",
+        options.strategy, options.word_length, options.attempts, games
+    );
+    println!(
+        "won {} of {} ({:.1} %), {:.2} guesses per win",
+        wins,
+        games,
+        100.0 * wins as f64 / games as f64,
+        if wins > 0 {
+            total_tries as f64 / wins as f64
+        } else {
+            0.0
+        }
+    );
+
+    let largest: u32 = distribution
+        .values()
+        .copied()
+        .chain([losses])
+        .max()
+        .unwrap_or(0);
+    let rows = distribution
+        .iter()
+        .map(|(tries, count)| (tries.to_string(), *count))
+        .chain([("X".to_string(), losses)]);
+    for (label, count) in rows {
+        let bar_length: u32 = if largest > 0 {
+            (count * BAR_WIDTH).div_ceil(largest)
+        } else {
+            0
+        };
+        println!(
+            "{:>2}: {:>6} {}",
+            label,
+            count,
+            "#".repeat(bar_length as usize)
+        );
+    }
+}
@@ -1,3 +1,5 @@
 pub mod graphics;
 
+pub mod keyboard;
+
 pub mod menu;
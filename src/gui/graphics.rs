@@ -1,9 +1,16 @@
 use macroquad::prelude::*;
 
-use crate::app::Settings;
-use crate::game::Game;
-use crate::game::Guess;
-use crate::letters::Letters;
+use crate::gui::keyboard;
+use crate::gui::keyboard::Keyboard;
+use czwordle_core::alphabet::Alphabet;
+use czwordle_core::boards::Boards;
+use czwordle_core::game::Game;
+use czwordle_core::game::GameState;
+use czwordle_core::game::Guess;
+use czwordle_core::layout::KeyKind;
+use czwordle_core::layout::KeyboardLayout;
+use czwordle_core::letters::Letters;
+use czwordle_core::statistics::Record;
 
 pub const BG_COLOR: Color = Color::new(0.92, 0.92, 0.91, 1.0);
 pub const FG_COLOR: Color = Color::new(0.2, 0.2, 0.2, 1.0);
@@ -11,11 +18,19 @@ pub const CORRECT_COLOR: Color = Color::new(0.11, 0.69, 0.13, 1.0);
 
 const YELLOW_COLOR: Color = Color::new(0.93, 0.79, 0.16, 1.0);
 const UNUSED_COLOR: Color = Color::new(0.83, 0.83, 0.83, 1.0);
+const USED_KEY_COLOR: Color = Color::new(0.55, 0.55, 0.55, 1.0);
+
+const TOAST_DURATION: f64 = 2.0;
+const HINT_DURATION: f64 = 6.0;
+const SHAKE_DURATION: f64 = 0.4;
 
 pub struct Graphics {
     font: TextParams,
     logo: Texture2D,
     box_textures: Vec<Texture2D>,
+    // the message and the time it disappears
+    toast: Option<(String, f64)>,
+    shake_start: Option<f64>,
 }
 
 impl Graphics {
@@ -27,6 +42,8 @@ impl Graphics {
             },
             logo,
             box_textures,
+            toast: None,
+            shake_start: None,
         }
     }
 
@@ -41,14 +58,77 @@ impl Graphics {
             WHITE,
         );
 
-        logo_y_start + self.logo.height() + 100.0
+        logo_y_start + self.logo.height() + 70.0
+    }
+
+    pub fn draw_game(&self, boards: &Boards, word: &str, keyboard: &Keyboard) {
+        macroquad::window::clear_background(BG_COLOR);
+
+        // a single board that does not fit above the keyboard shrinks like the boards do
+        if boards.get_board_count() == 1 && keyboard.get_top() >= self.get_board_bottom(boards) {
+            self.draw_words(
+                boards.get_word_length(),
+                word,
+                boards.get_games()[0].get_guesses(),
+            );
+        } else {
+            self.draw_boards(boards, Some(word), 10.0, keyboard.get_top() - 10.0);
+        }
+
+        if let (1, Some(puzzle_number)) = (boards.get_board_count(), boards.get_puzzle_number()) {
+            self.draw_puzzle_number(puzzle_number);
+        }
+        self.draw_keyboard(keyboard, boards);
+
+        self.draw_toast();
     }
 
-    pub fn draw_game(&self, settings: &Settings, game: &Game, word: &String) {
+    // Draws the finished boards with the missed words, returns where the menu starts.
+    pub fn draw_boards_over(&self, boards: &Boards) -> f32 {
         macroquad::window::clear_background(BG_COLOR);
 
-        self.draw_words(settings.word_length, word, game.get_guesses());
-        self.draw_letters(game.get_letters(), settings.attempts);
+        let bottom: f32 = screen_height() - 180.0;
+        self.draw_boards(boards, None, 10.0, bottom);
+
+        bottom + 50.0
+    }
+
+    pub fn show_toast(&mut self, message: &str) {
+        self.toast = Some((message.to_string(), get_time() + TOAST_DURATION));
+    }
+
+    // The hint is shown like a toast, only longer so that the words can be read.
+    pub fn show_hint(&mut self, message: &str) {
+        self.toast = Some((message.to_string(), get_time() + HINT_DURATION));
+    }
+
+    pub fn shake_current_row(&mut self) {
+        self.shake_start = Some(get_time());
+    }
+
+    pub fn draw_puzzle_number(&self, puzzle_number: u32) {
+        draw_text_ex(
+            &format!("#{}", puzzle_number),
+            10.0,
+            30.0,
+            TextParams {
+                font_size: 24,
+                ..self.font
+            },
+        );
+    }
+
+    // The seed of the random answers is shown in the menu, so that a bug report can name it.
+    pub fn draw_seed(&self, seed: u64) {
+        draw_text_ex(
+            &format!("seed {}", seed),
+            10.0,
+            30.0,
+            TextParams {
+                font_size: 24,
+                ..self.font
+            },
+        );
     }
 
     pub fn draw_win(&self, word_length: u32, past_words: &Vec<Guess>) -> f32 {
@@ -73,84 +153,166 @@ impl Graphics {
         }
 
         let start_y = 90.0 + past_words.len() as f32 * 60.0;
-        self.draw_lose_word(pos_x, start_y, &correct_word.to_string());
+        self.draw_lose_word(pos_x, start_y, correct_word);
 
         start_y + 80.0 + 60.0
     }
 
-    pub fn draw_letters(&self, letters: &Letters, total_guesses: u32) {
-        let rows = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
-        let diacritic_rows = ["ěščřžýáíé", "ďťňóúů"];
+    pub fn draw_statistics(&self, word_length: u32, attempts: u32, record: &Record) -> f32 {
+        macroquad::window::clear_background(BG_COLOR);
 
-        let start_y = (total_guesses + 1) as f32 * 70.0;
+        self.draw_centered_text(
+            &format!("STATISTICS {}/{}", word_length, attempts),
+            70.0,
+            CORRECT_COLOR,
+        );
 
-        let unused_params = TextParams {
-            color: FG_COLOR,
-            ..self.font
-        };
-        let used_params = TextParams {
-            color: UNUSED_COLOR,
+        let lines = [
+            format!("PLAYED {}", record.played),
+            format!("WIN % {}", record.get_win_percentage()),
+            format!("STREAK {}", record.current_streak),
+            format!("MAX STREAK {}", record.max_streak),
+        ];
+        for (i, line) in (0_usize..).zip(lines.iter()) {
+            self.draw_centered_text(line, 150.0 + i as f32 * 50.0, FG_COLOR);
+        }
+
+        let start_y: f32 = 380.0;
+        let row_height: f32 = f32::min(50.0, (screen_height() - start_y - 100.0) / attempts as f32);
+        let max_count: u32 = *record.guess_distribution.iter().max().unwrap_or(&0);
+        let max_width: f32 = screen_width() * 0.6;
+        let start_x: f32 = screen_width() * 0.2;
+        let small_font = TextParams {
+            font_size: 24,
             ..self.font
         };
-        let yellow_params = TextParams {
-            color: YELLOW_COLOR,
-            ..self.font
+
+        for (i, count) in (0_usize..).zip(record.guess_distribution.iter()) {
+            let pos_y: f32 = start_y + i as f32 * row_height;
+            let mut width: f32 = 30.0;
+            if max_count > 0 {
+                width = f32::max(width, max_width * *count as f32 / max_count as f32);
+            }
+
+            draw_text_ex(
+                &(i + 1).to_string(),
+                start_x - 30.0,
+                pos_y + row_height * 0.6,
+                small_font,
+            );
+            draw_rectangle(start_x, pos_y, width, row_height * 0.8, UNUSED_COLOR);
+            draw_text_ex(
+                &count.to_string(),
+                start_x + 5.0,
+                pos_y + row_height * 0.6,
+                small_font,
+            );
+        }
+
+        start_y + attempts as f32 * row_height + 60.0
+    }
+
+    // The keyboard sits below the board, the key rectangles are used both for drawing and for clicks.
+    pub fn get_keyboard(
+        &self,
+        boards: &Boards,
+        layout: &KeyboardLayout,
+        alphabet: &Alphabet,
+    ) -> Keyboard {
+        // the hint key goes to the shortest row, where it has the most space
+        let mut rows = layout.fit(alphabet);
+        if let Some(row) = rows.iter_mut().min_by_key(|row| row.len()) {
+            row.push(KeyKind::Hint);
+        }
+
+        // with more boards the board area is as large as possible and the keyboard sits at the bottom,
+        // a single board keeps its keyboard below it unless the window is too low
+        let bottom_y = screen_height() - rows.len() as f32 * keyboard::ROW_SPACING;
+        let start_y = if boards.get_board_count() == 1 {
+            f32::min(self.get_board_bottom(boards), bottom_y)
+        } else {
+            bottom_y
         };
-        let green_params = TextParams {
-            color: CORRECT_COLOR,
+
+        Keyboard::new(&rows, start_y, screen_width())
+    }
+
+    // Where the keyboard starts under a single board drawn with full sized boxes.
+    fn get_board_bottom(&self, boards: &Boards) -> f32 {
+        (boards.get_maximum_tries() + 1) as f32 * 70.0 - 35.0
+    }
+
+    // With more boards every key is split into one part per board, solved boards are not colored.
+    pub fn draw_keyboard(&self, keyboard: &Keyboard, boards: &Boards) {
+        let small_font = TextParams {
+            font_size: 20,
             ..self.font
         };
 
-        for (row_number, letter_row) in (0usize..).zip(rows) {
-            let start_x: f32 = macroquad::window::screen_width() / 2.0
-                - (letter_row.chars().count() as f32 * 40.0) / 2.0;
-            let pos_y: f32 = start_y + row_number as f32 * 55.0;
-
-            for (i, l) in (0usize..).zip(
-                letter_row
-                    .chars()
-                    .flat_map(|c| c.to_uppercase())
-                    .collect::<String>()
-                    .chars(),
-            ) {
-                let pos_x: f32 = start_x + i as f32 * 40.0;
-
-                if letters.get_green_letters().contains(&l) {
-                    draw_text_ex(&l.to_string(), pos_x, pos_y, green_params);
-                } else if letters.get_yellow_letters().contains(&l) {
-                    draw_text_ex(&l.to_string(), pos_x, pos_y, yellow_params);
-                } else if letters.get_used_letters().contains(&l) {
-                    draw_text_ex(&l.to_string(), pos_x, pos_y, used_params);
-                } else {
-                    draw_text_ex(&l.to_string(), pos_x, pos_y, unused_params);
+        let games: &Vec<Game> = boards.get_games();
+        let part_rows: usize = if games.len() > 2 { 2 } else { 1 };
+        let part_columns: usize = games.len() / part_rows;
+
+        for key in keyboard.get_keys() {
+            let (label, text_color, font) = match key.kind {
+                KeyKind::Letter(l) => {
+                    let part_width: f32 = key.rect.w / part_columns as f32;
+                    let part_height: f32 = key.rect.h / part_rows as f32;
+
+                    for (i, game) in (0usize..).zip(games) {
+                        let mut color: Color = UNUSED_COLOR;
+                        if games.len() == 1
+                            || matches!(game.get_game_state(), GameState::Ongoing(_))
+                        {
+                            color = get_key_colors(game.get_letters(), l).0;
+                        }
+
+                        draw_rectangle(
+                            key.rect.x + (i % part_columns) as f32 * part_width,
+                            key.rect.y + (i / part_columns) as f32 * part_height,
+                            part_width,
+                            part_height,
+                            color,
+                        );
+                    }
+
+                    // the letter stays dark over the parts of split keys
+                    let mut text_color: Color = FG_COLOR;
+                    if games.len() == 1 {
+                        text_color = get_key_colors(games[0].get_letters(), l).1;
+                    }
+                    (l.to_string(), text_color, self.font)
                 }
-            }
-        }
-
-        for (row_number, letter_row) in (0usize..).zip(diacritic_rows) {
-            let start_x: f32 = macroquad::window::screen_width() / 2.0
-                - (letter_row.chars().count() as f32 * 40.0) / 2.0;
-            let pos_y: f32 = start_y + (row_number + 4) as f32 * 55.0;
-
-            for (i, l) in (0usize..).zip(
-                letter_row
-                    .chars()
-                    .flat_map(|c| c.to_uppercase())
-                    .collect::<String>()
-                    .chars(),
-            ) {
-                let pos_x: f32 = start_x + i as f32 * 40.0;
-
-                if letters.get_green_letters().contains(&l) {
-                    draw_text_ex(&l.to_string(), pos_x, pos_y, green_params);
-                } else if letters.get_yellow_letters().contains(&l) {
-                    draw_text_ex(&l.to_string(), pos_x, pos_y, yellow_params);
-                } else if letters.get_used_letters().contains(&l) {
-                    draw_text_ex(&l.to_string(), pos_x, pos_y, used_params);
-                } else {
-                    draw_text_ex(&l.to_string(), pos_x, pos_y, unused_params);
+                KeyKind::Enter => {
+                    draw_rectangle(key.rect.x, key.rect.y, key.rect.w, key.rect.h, UNUSED_COLOR);
+                    ("ENTER".to_string(), FG_COLOR, small_font)
                 }
-            }
+                KeyKind::Backspace => {
+                    draw_rectangle(key.rect.x, key.rect.y, key.rect.w, key.rect.h, UNUSED_COLOR);
+                    ("DEL".to_string(), FG_COLOR, small_font)
+                }
+                KeyKind::Hint => {
+                    draw_rectangle(key.rect.x, key.rect.y, key.rect.w, key.rect.h, UNUSED_COLOR);
+                    ("HINT".to_string(), FG_COLOR, small_font)
+                }
+            };
+
+            let c = get_text_center(
+                &label,
+                Some(font.font),
+                font.font_size,
+                font.font_scale,
+                font.rotation,
+            );
+            draw_text_ex(
+                &label,
+                key.rect.x + key.rect.w / 2.0 - c.x,
+                key.rect.y + key.rect.h / 2.0 - c.y,
+                TextParams {
+                    color: text_color,
+                    ..font
+                },
+            );
         }
     }
 
@@ -167,28 +329,114 @@ impl Graphics {
     }
 
     fn draw_letter(&self, letter: &str, pos_x: f32, pos_y: f32, texture_index: usize) {
+        assert!(texture_index < self.box_textures.len());
+
+        let size: f32 = self.box_textures[texture_index].width();
+        self.draw_scaled_letter(letter, pos_x, pos_y, size, texture_index);
+    }
+
+    fn draw_scaled_letter(
+        &self,
+        letter: &str,
+        pos_x: f32,
+        pos_y: f32,
+        size: f32,
+        texture_index: usize,
+    ) {
         assert!(letter.chars().count() == 1);
         assert!(texture_index < self.box_textures.len());
 
+        let texture: Texture2D = self.box_textures[texture_index];
+        let font = TextParams {
+            font_scale: self.font.font_scale * size / texture.width(),
+            ..self.font
+        };
         let c = get_text_center(
             letter,
-            Some(self.font.font),
-            self.font.font_size,
-            self.font.font_scale,
-            self.font.rotation,
+            Some(font.font),
+            font.font_size,
+            font.font_scale,
+            font.rotation,
         );
 
-        let anchor_y = pos_y + self.box_textures[texture_index].height() * 0.35;
-        draw_texture(
-            self.box_textures[texture_index],
-            pos_x - self.box_textures[texture_index].width() / 2.0,
-            pos_y - self.box_textures[texture_index].height() / 2.0,
+        let anchor_y = pos_y + size * 0.35;
+        draw_texture_ex(
+            texture,
+            pos_x - size / 2.0,
+            pos_y - size / 2.0,
             WHITE,
+            DrawTextureParams {
+                dest_size: Some(vec2(size, size)),
+                ..Default::default()
+            },
         );
-        draw_text_ex(letter, pos_x - c.x, anchor_y, self.font);
+        draw_text_ex(letter, pos_x - c.x, anchor_y, font);
+    }
+
+    // Lays the boards out in up to four columns between `top` and `bottom`, the boxes shrink to fit.
+    // Without the current word the missed words of lost boards are shown in their last row.
+    fn draw_boards(&self, boards: &Boards, current_word: Option<&str>, top: f32, bottom: f32) {
+        let board_count: u32 = boards.get_board_count();
+        let columns: u32 = u32::min(board_count, 4);
+        let rows: u32 = board_count.div_ceil(columns);
+
+        let board_width: f32 = screen_width() / columns as f32;
+        let board_height: f32 = (bottom - top) / rows as f32;
+        let word_length: f32 = boards.get_word_length() as f32;
+        let row_count: f32 = boards.get_maximum_tries() as f32 + 1.0;
+
+        let spacing: f32 = f32::min(
+            self.box_textures[0].width() * 1.1,
+            f32::min(board_width / (word_length + 0.5), board_height / row_count),
+        );
+        let size: f32 = spacing / 1.1;
+
+        for (i, game) in (0_u32..).zip(boards.get_games()) {
+            let start_x: f32 = (i % columns) as f32 * board_width + board_width / 2.0
+                - (word_length - 1.0) * spacing / 2.0;
+            let start_y: f32 = top + (i / columns) as f32 * board_height + spacing / 2.0;
+
+            for (row, guess) in (0_u32..).zip(game.get_guesses()) {
+                for (position, letter) in (0_u32..).zip(guess.word.chars()) {
+                    let mut texture_index: usize = 2;
+                    if guess.green_positions.contains(&position) {
+                        texture_index = 1;
+                    } else if guess.yellow_positions.contains(&position) {
+                        texture_index = 0;
+                    }
+
+                    self.draw_scaled_letter(
+                        &letter.to_string(),
+                        start_x + position as f32 * spacing,
+                        start_y + row as f32 * spacing,
+                        size,
+                        texture_index,
+                    );
+                }
+            }
+
+            let (word, texture_index, shake_offset) = match (game.get_game_state(), current_word) {
+                (GameState::Ongoing(_), Some(word)) => {
+                    (word.to_string(), 2, self.get_shake_offset())
+                }
+                (GameState::Lose, None) => (game.get_correct_word(), 3, 0.0),
+                _ => continue,
+            };
+
+            let pos_y: f32 = start_y + game.get_guesses().len() as f32 * spacing;
+            for (position, letter) in (0_u32..).zip(word.chars()) {
+                self.draw_scaled_letter(
+                    &letter.to_string(),
+                    start_x + shake_offset + position as f32 * spacing,
+                    pos_y,
+                    size,
+                    texture_index,
+                );
+            }
+        }
     }
 
-    fn draw_words(&self, word_length: u32, current_word: &String, past_words: &Vec<Guess>) {
+    fn draw_words(&self, word_length: u32, current_word: &str, past_words: &Vec<Guess>) {
         let pos_x = self.get_center_for_boxes(word_length);
         for (i, guess) in (0_usize..).zip(past_words) {
             let pos_y = 60.0 + i as f32 * 60.0;
@@ -196,17 +444,70 @@ impl Graphics {
         }
 
         let pos_y = 60.0 + past_words.len() as f32 * 60.0;
-        self.draw_word(pos_x, pos_y, current_word);
+        self.draw_word(pos_x + self.get_shake_offset(), pos_y, current_word);
+    }
+
+    fn get_shake_offset(&self) -> f32 {
+        let elapsed: f64 = match self.shake_start {
+            Some(start) => get_time() - start,
+            None => return 0.0,
+        };
+
+        if elapsed > SHAKE_DURATION {
+            return 0.0;
+        }
+
+        let fade: f64 = 1.0 - elapsed / SHAKE_DURATION;
+        ((elapsed * 60.0).sin() * 12.0 * fade) as f32
     }
 
-    fn draw_word(&self, x: f32, y: f32, word: &String) {
+    fn draw_toast(&self) {
+        let (message, end) = match &self.toast {
+            Some(toast) => toast,
+            None => return,
+        };
+
+        if get_time() > *end {
+            return;
+        }
+
+        // long messages are drawn smaller to fit the window
+        let mut font: TextParams = self.font;
+        let mut dimensions: TextDimensions =
+            measure_text(message, Some(font.font), font.font_size, font.font_scale);
+        if dimensions.width + 40.0 > screen_width() {
+            font.font_size =
+                (font.font_size as f32 * (screen_width() - 40.0) / dimensions.width) as u16;
+            dimensions = measure_text(message, Some(font.font), font.font_size, font.font_scale);
+        }
+
+        let width: f32 = dimensions.width + 40.0;
+        draw_rectangle(
+            screen_width() / 2.0 - width / 2.0,
+            8.0,
+            width,
+            56.0,
+            FG_COLOR,
+        );
+        draw_text_ex(
+            message,
+            screen_width() / 2.0 - dimensions.width / 2.0,
+            50.0,
+            TextParams {
+                color: BG_COLOR,
+                ..font
+            },
+        );
+    }
+
+    fn draw_word(&self, x: f32, y: f32, word: &str) {
         let spacing: f32 = self.box_textures[0].width() * 1.1;
         for (i, c) in (0_usize..word.len()).zip(word.chars()) {
             self.draw_letter(&c.to_string(), x + i as f32 * spacing, y, 2);
         }
     }
 
-    fn draw_lose_word(&self, x: f32, y: f32, word: &String) {
+    fn draw_lose_word(&self, x: f32, y: f32, word: &str) {
         let spacing: f32 = self.box_textures[0].width() * 1.1;
         for (i, c) in (0_usize..word.len()).zip(word.chars()) {
             self.draw_letter(&c.to_string(), x + i as f32 * spacing, y, 3);
@@ -241,3 +542,15 @@ impl Graphics {
             + self.box_textures[0].width() / 2.0
     }
 }
+
+fn get_key_colors(letters: &Letters, letter: char) -> (Color, Color) {
+    if letters.get_green_letters().contains(&letter) {
+        (CORRECT_COLOR, BG_COLOR)
+    } else if letters.get_yellow_letters().contains(&letter) {
+        (YELLOW_COLOR, BG_COLOR)
+    } else if letters.get_used_letters().contains(&letter) {
+        (USED_KEY_COLOR, BG_COLOR)
+    } else {
+        (UNUSED_COLOR, FG_COLOR)
+    }
+}
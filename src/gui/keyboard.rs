@@ -0,0 +1,88 @@
+use macroquad::prelude::*;
+
+use czwordle_core::layout::KeyKind;
+
+#[cfg(test)]
+mod tests;
+
+const KEY_WIDTH: f32 = 36.0;
+const KEY_HEIGHT: f32 = 48.0;
+const KEY_SPACING: f32 = 40.0;
+pub const ROW_SPACING: f32 = 55.0;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Key {
+    pub kind: KeyKind,
+    pub rect: Rect,
+}
+
+pub struct Keyboard {
+    keys: Vec<Key>,
+}
+
+impl Keyboard {
+    // Lays out the rows centered on the screen, the action keys are twice as wide as the letters.
+    pub fn new(rows: &[Vec<KeyKind>], start_y: f32, screen_width: f32) -> Keyboard {
+        let mut keys: Vec<Key> = Vec::new();
+
+        for (row_number, row) in (0usize..).zip(rows) {
+            let pos_y: f32 = start_y + row_number as f32 * ROW_SPACING;
+            let row_width: f32 = row.iter().map(|kind| get_key_spacing(*kind)).sum();
+
+            let mut pos_x: f32 = screen_width / 2.0 - row_width / 2.0;
+            for kind in row {
+                let spacing: f32 = get_key_spacing(*kind);
+                keys.push(Key {
+                    kind: *kind,
+                    rect: Rect::new(pos_x, pos_y, spacing - KEY_SPACING + KEY_WIDTH, KEY_HEIGHT),
+                });
+                pos_x += spacing;
+            }
+        }
+
+        Keyboard { keys }
+    }
+
+    pub fn get_keys(&self) -> &Vec<Key> {
+        &self.keys
+    }
+
+    pub fn get_top(&self) -> f32 {
+        self.keys
+            .iter()
+            .map(|key| key.rect.y)
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    pub fn hit_test(&self, x: f32, y: f32) -> Option<KeyKind> {
+        self.keys
+            .iter()
+            .find(|key| key.rect.contains(vec2(x, y)))
+            .map(|key| key.kind)
+    }
+
+    // Keys hit by a mouse click or a new touch in this frame.
+    pub fn get_pressed_keys(&self) -> Vec<KeyKind> {
+        let mut result: Vec<KeyKind> = Vec::new();
+
+        if is_mouse_button_pressed(MouseButton::Left) {
+            let (x, y) = mouse_position();
+            result.extend(self.hit_test(x, y));
+        }
+
+        for touch in touches() {
+            if touch.phase == TouchPhase::Started {
+                result.extend(self.hit_test(touch.position.x, touch.position.y));
+            }
+        }
+
+        result
+    }
+}
+
+fn get_key_spacing(kind: KeyKind) -> f32 {
+    match kind {
+        KeyKind::Letter(_) => KEY_SPACING,
+        KeyKind::Enter | KeyKind::Backspace | KeyKind::Hint => 2.0 * KEY_SPACING,
+    }
+}
@@ -0,0 +1,59 @@
+use super::KeyKind;
+use super::Keyboard;
+
+fn make_rows() -> Vec<Vec<KeyKind>> {
+    vec![
+        vec![KeyKind::Letter('A'), KeyKind::Letter('B')],
+        vec![
+            KeyKind::Enter,
+            KeyKind::Letter('Č'),
+            KeyKind::Letter('D'),
+            KeyKind::Letter('E'),
+            KeyKind::Backspace,
+        ],
+    ]
+}
+
+#[test]
+fn keyboard_layout() {
+    let keyboard = Keyboard::new(&make_rows(), 100.0, 600.0);
+    let keys = keyboard.get_keys();
+
+    let kinds: Vec<KeyKind> = keys.iter().map(|key| key.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            KeyKind::Letter('A'),
+            KeyKind::Letter('B'),
+            KeyKind::Enter,
+            KeyKind::Letter('Č'),
+            KeyKind::Letter('D'),
+            KeyKind::Letter('E'),
+            KeyKind::Backspace,
+        ]
+    );
+    assert_eq!(keys[2].rect.w, 76.0);
+
+    // rows are centered
+    assert_eq!(keys[0].rect.x, 260.0);
+    assert_eq!(keys[2].rect.x, 160.0);
+    assert_eq!(keys[3].rect.x, 240.0);
+    assert_eq!(keys[6].rect.x, 360.0);
+    assert!(keys[3].rect.y > keys[0].rect.y);
+}
+
+#[test]
+fn hit_test() {
+    let keyboard = Keyboard::new(&make_rows(), 100.0, 600.0);
+
+    assert_eq!(keyboard.hit_test(270.0, 120.0), Some(KeyKind::Letter('A')));
+    assert_eq!(keyboard.hit_test(310.0, 120.0), Some(KeyKind::Letter('B')));
+    assert_eq!(keyboard.hit_test(170.0, 170.0), Some(KeyKind::Enter));
+    assert_eq!(keyboard.hit_test(250.0, 170.0), Some(KeyKind::Letter('Č')));
+    assert_eq!(keyboard.hit_test(400.0, 170.0), Some(KeyKind::Backspace));
+
+    // gaps between keys and outside of the keyboard
+    assert_eq!(keyboard.hit_test(298.0, 120.0), None);
+    assert_eq!(keyboard.hit_test(270.0, 50.0), None);
+    assert_eq!(keyboard.hit_test(10.0, 120.0), None);
+}
@@ -4,9 +4,26 @@ use std::cmp;
 use crate::gui::graphics;
 use crate::gui::graphics::Graphics;
 
-type InputCallback<'a, T> = Box<dyn FnMut(&mut u32, &mut T) + 'a>;
+#[cfg(test)]
+mod tests;
+
+type InputCallback<'a, T> = Box<dyn FnMut(&mut u32, &mut T, MenuInput) + 'a>;
 type ItemsCallback<'a, T> = Option<Box<dyn FnMut(&mut T, &Vec<String>) -> Vec<String> + 'a>>;
 
+// The text of an item is drawn on its baseline, the item reaches this part of the spacing above it.
+const ITEM_ASCENT: f32 = 0.75;
+
+// What the player did with the menu in this frame, by the keyboard, a mouse click or a touch.
+// A tap on an item selects it, a tap on the left or right third of the screen changes its value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MenuInput {
+    None,
+    Select,
+    Back,
+    Left,
+    Right,
+}
+
 pub struct Menu<'a, T: std::fmt::Debug + Copy> {
     items: Vec<String>,
     data: T,
@@ -19,7 +36,7 @@ impl<'a, T: std::fmt::Debug + Copy> Menu<'a, T> {
     pub fn new(
         items: Vec<String>,
         data: T,
-        callback: impl FnMut(&mut u32, &mut T) + 'a,
+        callback: impl FnMut(&mut u32, &mut T, MenuInput) + 'a,
     ) -> Menu<'a, T> {
         Menu {
             items,
@@ -33,7 +50,7 @@ impl<'a, T: std::fmt::Debug + Copy> Menu<'a, T> {
     pub fn new_with_items_callback(
         items: Vec<String>,
         data: T,
-        callback: impl FnMut(&mut u32, &mut T) + 'a,
+        callback: impl FnMut(&mut u32, &mut T, MenuInput) + 'a,
         items_callback: impl FnMut(&mut T, &Vec<String>) -> Vec<String> + 'a,
     ) -> Menu<'a, T> {
         Menu {
@@ -60,11 +77,36 @@ impl<'a, T: std::fmt::Debug + Copy> Menu<'a, T> {
             }
         }
 
-        (self.callback)(&mut self.position, &mut self.data);
+        let mut input: MenuInput = if is_key_pressed(KeyCode::Enter) {
+            MenuInput::Select
+        } else if is_key_pressed(KeyCode::Escape) {
+            MenuInput::Back
+        } else if is_key_pressed(KeyCode::Left) {
+            MenuInput::Left
+        } else if is_key_pressed(KeyCode::Right) {
+            MenuInput::Right
+        } else {
+            MenuInput::None
+        };
+
+        let spacing: f32 = self.get_spacing(y_start);
+        for (x, y) in get_taps() {
+            if let Some(position) = self.hit_test(y, y_start, spacing) {
+                self.position = position;
+                input = if x < screen_width() / 3.0 {
+                    MenuInput::Left
+                } else if x > screen_width() * 2.0 / 3.0 {
+                    MenuInput::Right
+                } else {
+                    MenuInput::Select
+                };
+            }
+        }
+
+        (self.callback)(&mut self.position, &mut self.data, input);
 
-        if self.items_callback.is_some() {
-            let modified_items: Vec<String> =
-                self.items_callback.as_mut().unwrap()(&mut self.data, &self.items);
+        if let Some(items_callback) = self.items_callback.as_mut() {
+            let modified_items: Vec<String> = items_callback(&mut self.data, &self.items);
             self.draw(&modified_items, y_start, graphics);
         } else {
             self.draw(&self.items, y_start, graphics);
@@ -73,7 +115,32 @@ impl<'a, T: std::fmt::Debug + Copy> Menu<'a, T> {
         self.data
     }
 
+    pub fn get_position(&self) -> u32 {
+        self.position
+    }
+
+    pub fn set_position(&mut self, position: u32) {
+        self.position = cmp::min(position, (self.items.len() - 1) as u32);
+    }
+
+    // Long menus are squeezed to fit the window.
+    fn get_spacing(&self, y_start: f32) -> f32 {
+        f32::min(50.0, (screen_height() - y_start) / self.items.len() as f32)
+    }
+
+    // The item drawn at the height `y`, if there is one.
+    fn hit_test(&self, y: f32, y_start: f32, spacing: f32) -> Option<u32> {
+        let position: f32 = ((y - y_start) / spacing + ITEM_ASCENT).floor();
+        if position < 0.0 || position >= self.items.len() as f32 {
+            return None;
+        }
+
+        Some(position as u32)
+    }
+
     fn draw(&self, items: &[String], y_start: f32, graphics: &mut Graphics) {
+        let spacing: f32 = self.get_spacing(y_start);
+
         for (num, item) in (0_u32..).zip(items.iter()) {
             let mut color: Color = graphics::FG_COLOR;
 
@@ -81,7 +148,24 @@ impl<'a, T: std::fmt::Debug + Copy> Menu<'a, T> {
                 color = graphics::CORRECT_COLOR;
             }
 
-            graphics.draw_centered_text(item.as_str(), y_start + 60.0 * num as f32, color);
+            graphics.draw_centered_text(item.as_str(), y_start + spacing * num as f32, color);
+        }
+    }
+}
+
+// Places hit by a mouse click or a new touch in this frame.
+fn get_taps() -> Vec<(f32, f32)> {
+    let mut result: Vec<(f32, f32)> = Vec::new();
+
+    if is_mouse_button_pressed(MouseButton::Left) {
+        result.push(mouse_position());
+    }
+
+    for touch in touches() {
+        if touch.phase == TouchPhase::Started {
+            result.push((touch.position.x, touch.position.y));
         }
     }
+
+    result
 }
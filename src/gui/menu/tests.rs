@@ -0,0 +1,26 @@
+use super::Menu;
+
+fn make_menu() -> Menu<'static, u32> {
+    Menu::new(
+        vec![
+            "NEW GAME".to_string(),
+            "MENU".to_string(),
+            "SHARE".to_string(),
+        ],
+        0,
+        |_, _, _| {},
+    )
+}
+
+#[test]
+fn hit_test() {
+    let menu = make_menu();
+
+    // the items are drawn on their baselines at 100, 150 and 200
+    assert_eq!(menu.hit_test(100.0, 100.0, 50.0), Some(0));
+    assert_eq!(menu.hit_test(70.0, 100.0, 50.0), Some(0));
+    assert_eq!(menu.hit_test(120.0, 100.0, 50.0), Some(1));
+    assert_eq!(menu.hit_test(190.0, 100.0, 50.0), Some(2));
+    assert_eq!(menu.hit_test(60.0, 100.0, 50.0), None);
+    assert_eq!(menu.hit_test(230.0, 100.0, 50.0), None);
+}
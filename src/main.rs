@@ -8,12 +8,10 @@ use app::ApplicationState;
 
 mod gui;
 
-mod dictionary;
-use dictionary::Dictionary;
+use czwordle_core::dictionary::Dictionary;
+use czwordle_core::random;
 
-mod letters;
-
-mod game;
+mod storage;
 
 async fn load_fonts(path: &str) -> TextParams {
     let pf = load_ttf_font(path).await;
@@ -29,6 +27,18 @@ async fn load_fonts(path: &str) -> TextParams {
     }
 }
 
+// The web version has no arguments and is always seeded by the clock.
+fn get_seed() -> u64 {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match random::parse_seed(&args) {
+        Ok(seed) => seed.unwrap_or_else(|| instant::now() as u64),
+        Err(message) => {
+            println!("ERROR: {}", message);
+            std::process::exit(1);
+        }
+    }
+}
+
 fn window_conf() -> Conf {
     Conf {
         window_title: "czWORDLE".to_owned(),
@@ -47,30 +57,45 @@ async fn main() {
     boxes.push(load_texture("textures/gray.png").await.unwrap());
     boxes.push(load_texture("textures/red.png").await.unwrap());
 
+    let seed: u64 = get_seed();
+
     let mut app = App::new(
         std::include_str!("../data/dictionary.txt"),
+        std::include_str!("../data/guesses.txt"),
         load_fonts("ttf/NotoSansMono-Regular.ttf").await,
         load_texture("textures/logo.png").await.unwrap(),
         boxes,
+        seed,
     );
 
+    app.load_catalog().await;
+    app.load_layouts().await;
+    app.load_dictionary().await;
+
     let mut dictionary: Dictionary = app.make_dictionary();
 
-    macroquad::rand::srand(instant::now() as u64);
+    // touches are handled by the on-screen keyboard, they must not also click as the mouse
+    simulate_mouse_with_touch(false);
 
     let mut application_state: ApplicationState = ApplicationState::Menu;
 
     loop {
         if application_state != ApplicationState::NewGame {
-            let result: ApplicationState = app.run_menu_loop().await;
-            match result {
+            application_state = app.run_menu_loop(&mut dictionary).await;
+            match application_state {
                 ApplicationState::Quit => return,
-                ApplicationState::NewGame => {}
+                ApplicationState::Statistics => {
+                    application_state = app.run_statistics_loop().await;
+                    continue;
+                }
+                ApplicationState::NewGame
+                | ApplicationState::Daily
+                | ApplicationState::Continue => {}
                 _ => panic!("this should never happen"),
             }
         }
 
-        application_state = app.run_game_loop(&mut dictionary).await;
+        application_state = app.run_game_loop(&dictionary, application_state).await;
         match application_state {
             ApplicationState::Quit => return,
             ApplicationState::Game => panic!("this should never happen"),
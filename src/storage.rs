@@ -0,0 +1,57 @@
+// Key-value storage that survives restarts of the application.
+// Native builds keep one file per key in the user's config directory,
+// wasm builds use the browser's localStorage through a plugin in `index.html`.
+
+#[cfg(not(target_arch = "wasm32"))]
+use czwordle_core::storage;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load(key: &str) -> Option<String> {
+    storage::load(&dirs::config_dir()?, key)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save(key: &str, value: &str) {
+    if let Some(directory) = dirs::config_dir() {
+        storage::save(&directory, key, value);
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+extern "C" {
+    fn czwordle_storage_length(key: *const u8, key_length: u32) -> i32;
+    fn czwordle_storage_take(key: *const u8, key_length: u32, buffer: *mut u8, max_length: u32);
+    fn czwordle_storage_set(key: *const u8, key_length: u32, value: *const u8, value_length: u32);
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn load(key: &str) -> Option<String> {
+    let length = unsafe { czwordle_storage_length(key.as_ptr(), key.len() as u32) };
+    if length < 0 {
+        return None;
+    }
+
+    let mut buffer: Vec<u8> = vec![0; length as usize];
+    unsafe {
+        czwordle_storage_take(
+            key.as_ptr(),
+            key.len() as u32,
+            buffer.as_mut_ptr(),
+            buffer.len() as u32,
+        );
+    }
+
+    String::from_utf8(buffer).ok()
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn save(key: &str, value: &str) {
+    unsafe {
+        czwordle_storage_set(
+            key.as_ptr(),
+            key.len() as u32,
+            value.as_ptr(),
+            value.len() as u32,
+        );
+    }
+}